@@ -61,28 +61,7 @@ impl EventPublisher for NatsEventPublisher {
             .map_err(|e| PublishError::SerializationError(e.to_string()))?;
 
         // Extract correlation ID for header
-        let correlation_id = match event {
-            OrganizationEvent::OrganizationCreated(e) => &e.identity.correlation_id,
-            OrganizationEvent::OrganizationUpdated(e) => &e.identity.correlation_id,
-            OrganizationEvent::OrganizationStatusChanged(e) => &e.identity.correlation_id,
-            OrganizationEvent::OrganizationDissolved(e) => &e.identity.correlation_id,
-            OrganizationEvent::OrganizationMerged(e) => &e.identity.correlation_id,
-            OrganizationEvent::DepartmentCreated(e) => &e.identity.correlation_id,
-            OrganizationEvent::DepartmentUpdated(e) => &e.identity.correlation_id,
-            OrganizationEvent::DepartmentRestructured(e) => &e.identity.correlation_id,
-            OrganizationEvent::DepartmentDissolved(e) => &e.identity.correlation_id,
-            OrganizationEvent::TeamFormed(e) => &e.identity.correlation_id,
-            OrganizationEvent::TeamUpdated(e) => &e.identity.correlation_id,
-            OrganizationEvent::TeamDisbanded(e) => &e.identity.correlation_id,
-            OrganizationEvent::RoleCreated(e) => &e.identity.correlation_id,
-            OrganizationEvent::RoleUpdated(e) => &e.identity.correlation_id,
-            OrganizationEvent::RoleDeprecated(e) => &e.identity.correlation_id,
-            OrganizationEvent::FacilityCreated(e) => &e.identity.correlation_id,
-            OrganizationEvent::FacilityUpdated(e) => &e.identity.correlation_id,
-            OrganizationEvent::FacilityRemoved(e) => &e.identity.correlation_id,
-            OrganizationEvent::ChildOrganizationAdded(e) => &e.identity.correlation_id,
-            OrganizationEvent::ChildOrganizationRemoved(e) => &e.identity.correlation_id,
-        };
+        let correlation_id = &event.identity().correlation_id;
 
         // Add correlation ID as header for efficient querying
         let mut headers = async_nats::HeaderMap::new();
@@ -237,6 +216,7 @@ impl EventPublisher for NatsEventPublisher {
                 OrganizationEvent::OrganizationMerged(e) => e.effective_date,
                 OrganizationEvent::OrganizationCreated(e) => e.occurred_at,
                 OrganizationEvent::OrganizationUpdated(e) => e.occurred_at,
+                OrganizationEvent::OrganizationMetadataUpdated(e) => e.occurred_at,
                 OrganizationEvent::OrganizationStatusChanged(e) => e.occurred_at,
                 OrganizationEvent::DepartmentCreated(e) => e.occurred_at,
                 OrganizationEvent::DepartmentUpdated(e) => e.occurred_at,
@@ -253,6 +233,8 @@ impl EventPublisher for NatsEventPublisher {
                 OrganizationEvent::FacilityRemoved(e) => e.occurred_at,
                 OrganizationEvent::ChildOrganizationAdded(e) => e.occurred_at,
                 OrganizationEvent::ChildOrganizationRemoved(e) => e.occurred_at,
+                OrganizationEvent::OrganizationTagAdded(e) => e.occurred_at,
+                OrganizationEvent::OrganizationTagRemoved(e) => e.occurred_at,
             };
 
             if event_time >= start && event_time <= end {
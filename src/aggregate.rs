@@ -3,19 +3,19 @@
 //! The aggregate root for the organization domain, handling commands
 //! and emitting events.
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use cim_domain::{
-    AggregateRoot, EntityId, MealyStateMachine,
+    AggregateRoot, EntityId, MealyStateMachine, MessageIdentity,
 };
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
 use crate::{
     commands::*,
     entity::*,
     events::*,
-    OrganizationError, OrganizationResult,
+    OrganizationError, OrganizationResult, SizeCategory,
 };
 
 /// Organization aggregate state for MealyStateMachine
@@ -35,6 +35,8 @@ pub enum OrganizationState {
     Dissolved,
     /// Organization merged into another (terminal state)
     Merged,
+    /// Organization acquired by another and kept as a subsidiary
+    Acquired,
 }
 
 impl From<OrganizationStatus> for OrganizationState {
@@ -46,6 +48,56 @@ impl From<OrganizationStatus> for OrganizationState {
             OrganizationStatus::Suspended => OrganizationState::Suspended,
             OrganizationStatus::Dissolved => OrganizationState::Dissolved,
             OrganizationStatus::Merged => OrganizationState::Merged,
+            OrganizationStatus::Acquired => OrganizationState::Acquired,
+        }
+    }
+}
+
+/// Command gate per organization status, checked before any handler runs
+impl OrganizationStatus {
+    /// Whether an organization in this status accepts the given command
+    ///
+    /// Only an active organization, or an acquired one operating as a
+    /// subsidiary, can be restructured. Other statuses admit lifecycle and
+    /// tagging commands, and a suspended organization may still manage its
+    /// facilities. Dissolved and merged organizations accept nothing.
+    pub fn allows_command(&self, command: &OrganizationCommand) -> bool {
+        use OrganizationCommand::*;
+
+        match self {
+            OrganizationStatus::Active | OrganizationStatus::Acquired => true,
+            OrganizationStatus::Pending => matches!(
+                command,
+                CreateOrganization(_)
+                    | UpdateOrganization(_)
+                    | UpdateOrganizationMetadata(_)
+                    | ChangeOrganizationStatus(_)
+                    | AddOrganizationTag(_)
+                    | RemoveOrganizationTag(_)
+            ),
+            OrganizationStatus::Inactive => matches!(
+                command,
+                UpdateOrganization(_)
+                    | UpdateOrganizationMetadata(_)
+                    | ChangeOrganizationStatus(_)
+                    | MergeOrganizations(_)
+                    | CompleteMerge(_)
+                    | AddOrganizationTag(_)
+                    | RemoveOrganizationTag(_)
+            ),
+            OrganizationStatus::Suspended => matches!(
+                command,
+                UpdateOrganization(_)
+                    | UpdateOrganizationMetadata(_)
+                    | ChangeOrganizationStatus(_)
+                    | DissolveOrganization(_)
+                    | AddOrganizationTag(_)
+                    | RemoveOrganizationTag(_)
+                    | CreateFacility(_)
+                    | UpdateFacility(_)
+                    | RemoveFacility(_)
+            ),
+            OrganizationStatus::Dissolved | OrganizationStatus::Merged => false,
         }
     }
 }
@@ -69,11 +121,30 @@ pub struct OrganizationAggregate {
     pub teams: HashMap<EntityId<Team>, Team>,
     pub roles: HashMap<EntityId<Role>, Role>,
     pub facilities: HashMap<EntityId<Facility>, Facility>,
+    /// Optional cap on the number of executive roles
+    #[serde(default)]
+    pub max_executives: Option<usize>,
+    /// Optional cap on the number of layers in any reporting chain
+    #[serde(default)]
+    pub max_reporting_depth: Option<usize>,
+    /// Free-form labels, normalized to lowercase
+    #[serde(default)]
+    pub tags: HashSet<String>,
     pub version: u64,
+    /// When the organization was created; taken from `OrganizationCreated` on replay.
+    /// `None` for an empty aggregate and for snapshots taken before this was tracked
+    #[serde(default)]
+    pub created_at: Option<DateTime<Utc>>,
+    /// When the last applied event occurred; `None` under the same conditions
+    #[serde(default)]
+    pub updated_at: Option<DateTime<Utc>>,
+    /// Events applied by `execute` but not yet persisted
+    #[serde(skip)]
+    uncommitted: Vec<OrganizationEvent>,
 }
 
 /// Child organization
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ChildOrganization {
     pub id: Uuid,
     pub name: String,
@@ -100,6 +171,111 @@ pub enum Permission {
     // NOT here - Organization domain doesn't manage people
 }
 
+impl Permission {
+    /// Name used when the permission is stored on a role
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Permission::CreateOrganization => "CreateOrganization",
+            Permission::DeleteOrganization => "DeleteOrganization",
+            Permission::ModifyOrganization => "ModifyOrganization",
+            Permission::ViewOrganization => "ViewOrganization",
+            Permission::ApproveBudget => "ApproveBudget",
+            Permission::ManageDepartment => "ManageDepartment",
+            Permission::ManageTeam => "ManageTeam",
+            Permission::CreateRole => "CreateRole",
+            Permission::ModifyRole => "ModifyRole",
+            Permission::CreateFacility => "CreateFacility",
+            Permission::ModifyFacility => "ModifyFacility",
+            Permission::ViewReports => "ViewReports",
+        }
+    }
+}
+
+/// How an organization's reporting depth compares with its size category
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayerAssessment {
+    UnderLayered,
+    WithinGuidance,
+    OverLayered,
+}
+
+/// Actual versus recommended management layers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManagementLayerReport {
+    pub size_category: SizeCategory,
+    pub recommended_layers: usize,
+    pub actual_layers: usize,
+    pub assessment: LayerAssessment,
+}
+
+/// Output format for `OrganizationAggregate::export_reporting_chain`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChainExportFormat {
+    /// Header row, then one row per role
+    Csv,
+    /// Array of role objects
+    Json,
+}
+
+/// A proposed reporting line change; see `OrganizationAggregate::suggest_rebalance`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportingChange {
+    pub role_id: EntityId<Role>,
+    pub current_manager: Option<EntityId<Role>>,
+    pub proposed_manager: Option<EntityId<Role>>,
+}
+
+/// Advisory finding from a structure audit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StructureWarning {
+    /// No active executive role heads the organization
+    NoExecutive,
+    /// The organization type is not expected to own child organizations
+    UnexpectedChildOrganizations { count: usize },
+}
+
+/// Structural expectations checked by `OrganizationAggregate::validate_structure`
+///
+/// Rules only report; they never reject commands, so audits can run them in
+/// the background against live aggregates.
+pub trait StructureRules {
+    fn check(&self, aggregate: &OrganizationAggregate) -> Vec<StructureWarning>;
+}
+
+/// Default rules per organization type
+///
+/// Incorporated bodies, cooperatives, nonprofits and government bodies are
+/// expected to have an executive; sole proprietorships are not expected to
+/// own child organizations.
+impl StructureRules for OrganizationType {
+    fn check(&self, aggregate: &OrganizationAggregate) -> Vec<StructureWarning> {
+        let mut warnings = Vec::new();
+        match self {
+            OrganizationType::Corporation
+            | OrganizationType::LLC
+            | OrganizationType::Cooperative
+            | OrganizationType::NonProfit
+            | OrganizationType::Government => {
+                let has_executive = aggregate.roles.values().any(|role| {
+                    role.role_type == RoleType::Executive && role.status == RoleStatus::Active
+                });
+                if !has_executive {
+                    warnings.push(StructureWarning::NoExecutive);
+                }
+            }
+            OrganizationType::SoleProprietorship => {
+                if !aggregate.child_organizations.is_empty() {
+                    warnings.push(StructureWarning::UnexpectedChildOrganizations {
+                        count: aggregate.child_organizations.len(),
+                    });
+                }
+            }
+            OrganizationType::Partnership | OrganizationType::Other(_) => {}
+        }
+        warnings
+    }
+}
+
 impl OrganizationAggregate {
     /// Create an empty aggregate (used when creating organization via command)
     pub fn empty() -> Self {
@@ -114,12 +290,19 @@ impl OrganizationAggregate {
             teams: HashMap::new(),
             roles: HashMap::new(),
             facilities: HashMap::new(),
+            max_executives: None,
+            max_reporting_depth: None,
+            tags: HashSet::new(),
             version: 0,
+            created_at: None,
+            updated_at: None,
+            uncommitted: Vec::new(),
         }
     }
 
     /// Create a new aggregate with organization details
     pub fn new(id: Uuid, name: String, org_type: OrganizationType) -> Self {
+        let now = Utc::now();
         let org = Organization {
             id: EntityId::from_uuid(id),
             name: name.clone(),
@@ -130,8 +313,8 @@ impl OrganizationAggregate {
             status: OrganizationStatus::Pending,
             founded_date: None,
             metadata: serde_json::Value::Object(serde_json::Map::new()),
-            created_at: Utc::now(),
-            updated_at: Utc::now(),
+            created_at: now,
+            updated_at: now,
         };
 
         Self {
@@ -145,12 +328,26 @@ impl OrganizationAggregate {
             teams: HashMap::new(),
             roles: HashMap::new(),
             facilities: HashMap::new(),
+            max_executives: None,
+            max_reporting_depth: None,
+            tags: HashSet::new(),
             version: 0,
+            created_at: Some(now),
+            updated_at: Some(now),
+            uncommitted: Vec::new(),
         }
     }
 
+    /// Like `new`, with the roles map pre-sized for bulk seeding
+    pub fn with_capacity(id: Uuid, name: String, org_type: OrganizationType, role_capacity: usize) -> Self {
+        let mut aggregate = Self::new(id, name, org_type);
+        aggregate.roles.reserve(role_capacity);
+        aggregate
+    }
+
     /// Create aggregate with existing organization
     pub fn from_organization(org: Organization) -> Self {
+        let (created_at, updated_at) = (org.created_at, org.updated_at);
         Self {
             id: org.id.clone().into(),
             name: org.name.clone(),
@@ -162,15 +359,502 @@ impl OrganizationAggregate {
             teams: HashMap::new(),
             roles: HashMap::new(),
             facilities: HashMap::new(),
+            max_executives: None,
+            max_reporting_depth: None,
+            tags: HashSet::new(),
             version: 0,
+            created_at: Some(created_at),
+            updated_at: Some(updated_at),
+            uncommitted: Vec::new(),
         }
     }
 
+    /// Cap the number of executive roles this organization may hold
+    pub fn with_max_executives(mut self, max_executives: usize) -> Self {
+        self.max_executives = Some(max_executives);
+        self
+    }
+
+    /// Cap the number of layers in any reporting chain
+    pub fn with_max_reporting_depth(mut self, max_reporting_depth: usize) -> Self {
+        self.max_reporting_depth = Some(max_reporting_depth);
+        self
+    }
+
     /// Get the aggregate root ID (Organization ID if it exists)
     pub fn aggregate_id(&self) -> Option<EntityId<Organization>> {
         self.organization.as_ref().map(|org| org.id.clone())
     }
 
+    /// Compare two aggregates ignoring `version` and the aggregate timestamps
+    ///
+    /// Useful when comparing an aggregate with one reconstructed from a snapshot
+    /// or a replay, where the version count may legitimately differ.
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.name == other.name
+            && self.org_type == other.org_type
+            && self.status == other.status
+            && self.child_organizations == other.child_organizations
+            && self.organization == other.organization
+            && self.departments == other.departments
+            && self.teams == other.teams
+            && self.roles == other.roles
+            && self.facilities == other.facilities
+            && self.max_executives == other.max_executives
+            && self.max_reporting_depth == other.max_reporting_depth
+            && self.tags == other.tags
+    }
+
+    /// Whether the organization carries the given tag (case-insensitive)
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.contains(&normalize_tag(tag))
+    }
+
+    /// The position to contact on behalf of the organization
+    ///
+    /// Picks the highest-level active role, tie-broken by the earliest
+    /// created; roles without a level rank last. Returns the role id; the
+    /// person holding it is resolved through the Association domain.
+    pub fn primary_contact(&self) -> Option<EntityId<Role>> {
+        self.roles
+            .values()
+            .filter(|role| role.status == RoleStatus::Active)
+            .min_by_key(|role| (std::cmp::Reverse(role.level), role.created_at, role.id.to_string()))
+            .map(|role| role.id.clone())
+    }
+
+    /// Active roles (positions) that grant the given permission
+    ///
+    /// Answers "which positions can approve budgets?". Resolving the people
+    /// holding those positions is done through the Association domain.
+    pub fn roles_with_permission(&self, permission: &Permission) -> Vec<&Role> {
+        self.roles
+            .values()
+            .filter(|role| role.status == RoleStatus::Active && role.has_permission(permission))
+            .collect()
+    }
+
+    /// Non-deprecated roles whose title matches, ignoring case and surrounding whitespace
+    ///
+    /// Answers "which positions are Software Engineers?", oldest first. The
+    /// people holding them are resolved through the Association domain.
+    pub fn find_roles_by_title(&self, title: &str) -> Vec<&Role> {
+        let title = title.trim();
+        let mut roles: Vec<&Role> = self
+            .roles
+            .values()
+            .filter(|role| role.status != RoleStatus::Deprecated && role.title.trim().eq_ignore_ascii_case(title))
+            .collect();
+        roles.sort_by_key(|role| (role.created_at, role.id.to_string()));
+        roles
+    }
+
+    /// Number of non-deprecated roles (positions)
+    pub fn role_count(&self) -> usize {
+        self.roles
+            .values()
+            .filter(|role| role.status != RoleStatus::Deprecated)
+            .count()
+    }
+
+    /// Roles marked vacant, oldest first, as candidates for cleanup
+    ///
+    /// Who holds a role is tracked by assignments in the Association domain;
+    /// here that shows up only as a role's `Vacant` status.
+    pub fn unused_roles(&self) -> Vec<EntityId<Role>> {
+        let mut vacant: Vec<&Role> = self
+            .roles
+            .values()
+            .filter(|role| role.status == RoleStatus::Vacant)
+            .collect();
+        vacant.sort_by_key(|role| (role.created_at, role.id.to_string()));
+        vacant.into_iter().map(|role| role.id.clone()).collect()
+    }
+
+    /// Count of non-deprecated roles (positions) per role type
+    ///
+    /// Lets command-side policies such as an executive cap reason about the
+    /// structure without going through a query layer.
+    pub fn role_count_by_type(&self) -> HashMap<RoleType, usize> {
+        let mut counts = HashMap::new();
+        for role in self.roles.values().filter(|role| role.status != RoleStatus::Deprecated) {
+            *counts.entry(role.role_type.clone()).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Reporting chain from a role up to the top of its hierarchy
+    ///
+    /// Starts with the role itself. Stops at a role whose manager is unknown,
+    /// or before revisiting a role if `reports_to` forms a cycle.
+    pub fn reporting_chain(&self, role_id: &EntityId<Role>) -> Vec<&Role> {
+        let mut chain: Vec<&Role> = Vec::new();
+        let mut next = self.roles.get(role_id);
+        while let Some(role) = next {
+            if chain.iter().any(|seen| seen.id == role.id) {
+                break;
+            }
+            chain.push(role);
+            next = role.reports_to.as_ref().and_then(|manager| self.roles.get(manager));
+        }
+        chain
+    }
+
+    /// Render a role's reporting chain for approval-audit attachments
+    ///
+    /// One entry per role, from the role itself (position 0) up to the top
+    /// of its hierarchy, with its id, code, title and level.
+    pub fn export_reporting_chain(&self, role_id: &EntityId<Role>, format: ChainExportFormat) -> OrganizationResult<String> {
+        self.validate_role_exists(role_id)?;
+        let chain = self.reporting_chain(role_id);
+
+        match format {
+            ChainExportFormat::Csv => {
+                let mut csv = String::from("position,role_id,code,title,level\n");
+                for (position, role) in chain.iter().enumerate() {
+                    let level = role.level.map(|level| level.to_string()).unwrap_or_default();
+                    csv.push_str(&format!(
+                        "{},{},{},{},{}\n",
+                        position,
+                        role.id,
+                        csv_field(&role.code),
+                        csv_field(&role.title),
+                        level
+                    ));
+                }
+                Ok(csv)
+            }
+            ChainExportFormat::Json => {
+                let rows: Vec<serde_json::Value> = chain
+                    .iter()
+                    .enumerate()
+                    .map(|(position, role)| serde_json::json!({
+                        "position": position,
+                        "role_id": role.id.to_string(),
+                        "code": role.code,
+                        "title": role.title,
+                        "level": role.level,
+                    }))
+                    .collect();
+                serde_json::to_string(&rows).map_err(|e| OrganizationError::Serialization(e.to_string()))
+            }
+        }
+    }
+
+    /// Roles on a reporting cycle reachable from the given role, if any
+    fn reporting_cycle(&self, role_id: &EntityId<Role>) -> Option<Vec<EntityId<Role>>> {
+        let mut path: Vec<EntityId<Role>> = Vec::new();
+        let mut next = self.roles.get(role_id);
+        while let Some(role) = next {
+            if let Some(start) = path.iter().position(|seen| *seen == role.id) {
+                return Some(path.split_off(start));
+            }
+            path.push(role.id.clone());
+            next = role.reports_to.as_ref().and_then(|manager| self.roles.get(manager));
+        }
+        None
+    }
+
+    /// Check the whole `reports_to` graph for cycles
+    ///
+    /// Command handlers only validate the change at hand; state that bypassed
+    /// them, such as a restored snapshot, is checked here. The error lists the
+    /// roles on the first cycle found.
+    pub fn validate_reporting_acyclic(&self) -> OrganizationResult<()> {
+        let mut cleared: HashSet<&EntityId<Role>> = HashSet::new();
+        let mut role_ids: Vec<&EntityId<Role>> = self.roles.keys().collect();
+        role_ids.sort_by_key(|role_id| role_id.to_string());
+
+        for start in role_ids {
+            let mut path: Vec<&EntityId<Role>> = Vec::new();
+            let mut next = Some(start);
+            while let Some(role_id) = next {
+                if cleared.contains(role_id) {
+                    break;
+                }
+                if let Some(cycle_start) = path.iter().position(|seen| *seen == role_id) {
+                    return Err(OrganizationError::CircularReference(format!(
+                        "Reporting cycle: {}",
+                        format_role_ids(path[cycle_start..].iter().copied())
+                    )));
+                }
+                path.push(role_id);
+                next = self.roles[role_id]
+                    .reports_to
+                    .as_ref()
+                    .filter(|manager| self.roles.contains_key(*manager));
+            }
+            cleared.extend(path);
+        }
+        Ok(())
+    }
+
+    /// Check state-wide invariants the command handlers only enforce per change
+    ///
+    /// The reporting graph must be acyclic, and every role and team
+    /// `department_id`, and every live department's parent, must name a
+    /// department of this organization that has not been dissolved. The
+    /// error lists the departments, roles and teams holding such a reference.
+    pub fn validate_invariants(&self) -> OrganizationResult<()> {
+        self.validate_reporting_acyclic()?;
+
+        let dangling = |department_id: &Option<EntityId<Department>>| {
+            department_id.as_ref().is_some_and(|id| {
+                self.departments
+                    .get(id)
+                    .is_none_or(|dept| dept.status == DepartmentStatus::Dissolved)
+            })
+        };
+        let mut departments: Vec<String> = self
+            .departments
+            .values()
+            .filter(|dept| dept.status != DepartmentStatus::Dissolved)
+            .filter(|dept| dangling(&dept.parent_department_id))
+            .map(|dept| dept.id.to_string())
+            .collect();
+        departments.sort();
+        let mut roles: Vec<&EntityId<Role>> = self
+            .roles
+            .values()
+            .filter(|role| dangling(&role.department_id))
+            .map(|role| &role.id)
+            .collect();
+        roles.sort_by_key(|role_id| role_id.to_string());
+        let mut teams: Vec<String> = self
+            .teams
+            .values()
+            .filter(|team| dangling(&team.department_id))
+            .map(|team| team.id.to_string())
+            .collect();
+        teams.sort();
+
+        if departments.is_empty() && roles.is_empty() && teams.is_empty() {
+            return Ok(());
+        }
+        Err(OrganizationError::InvalidStructure(format!(
+            "Unknown or dissolved department referenced by departments [{}], roles [{}] and teams [{}]",
+            departments.join(", "),
+            format_role_ids(roles),
+            teams.join(", ")
+        )))
+    }
+
+    /// Number of layers in the deepest reporting chain of non-deprecated roles
+    pub fn reporting_depth(&self) -> usize {
+        self.roles
+            .values()
+            .filter(|role| role.status != RoleStatus::Deprecated)
+            .map(|role| self.reporting_chain(&role.id).len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Non-deprecated roles in org-chart order
+    ///
+    /// Depth-first pre-order: each manager is followed by its reports.
+    /// Roles without a (non-deprecated) manager are roots. Siblings and roots
+    /// are ordered by level, highest first, then by id. Roles reachable only
+    /// through a reporting cycle come last, in the same order.
+    pub fn roles_sorted_by_hierarchy(&self) -> Vec<&Role> {
+        let mut active: Vec<&Role> = self
+            .roles
+            .values()
+            .filter(|role| role.status != RoleStatus::Deprecated)
+            .collect();
+        active.sort_by_key(|role| (std::cmp::Reverse(role.level), role.id.to_string()));
+
+        let active_ids: HashSet<&EntityId<Role>> = active.iter().map(|role| &role.id).collect();
+        let mut reports: HashMap<&EntityId<Role>, Vec<&Role>> = HashMap::new();
+        let mut roots: Vec<&Role> = Vec::new();
+        for role in &active {
+            match role.reports_to.as_ref().filter(|manager| active_ids.contains(manager)) {
+                Some(manager) => reports.entry(manager).or_default().push(role),
+                None => roots.push(role),
+            }
+        }
+        // Cycle members have no root above them; start from them once the
+        // proper roots are exhausted
+        roots.extend(active.iter().copied());
+
+        let mut ordered: Vec<&Role> = Vec::with_capacity(active.len());
+        let mut visited: HashSet<&EntityId<Role>> = HashSet::new();
+        for root in roots {
+            let mut pending = vec![root];
+            while let Some(role) = pending.pop() {
+                if !visited.insert(&role.id) {
+                    continue;
+                }
+                ordered.push(role);
+                if let Some(children) = reports.get(&role.id) {
+                    pending.extend(children.iter().rev());
+                }
+            }
+        }
+        ordered
+    }
+
+    /// Compare the reporting depth with the layers typical for `size_category`
+    pub fn management_layer_report(&self, size_category: SizeCategory) -> ManagementLayerReport {
+        let recommended_layers = size_category.typical_management_layers() as usize;
+        let actual_layers = self.reporting_depth();
+        let assessment = match actual_layers.cmp(&recommended_layers) {
+            std::cmp::Ordering::Greater => LayerAssessment::OverLayered,
+            std::cmp::Ordering::Less => LayerAssessment::UnderLayered,
+            std::cmp::Ordering::Equal => LayerAssessment::WithinGuidance,
+        };
+
+        ManagementLayerReport {
+            size_category,
+            recommended_layers,
+            actual_layers,
+            assessment,
+        }
+    }
+
+    /// Propose reporting changes that flatten the hierarchy to `target_depth` layers
+    ///
+    /// Walks the org chart top-down; a role that would sit below the target
+    /// depth is promoted to report to its ancestor on the last layer above
+    /// it, so its own reports follow it up. Nothing is applied, and a
+    /// hierarchy already within the target yields no suggestions. Roles on a
+    /// reporting cycle are left where they are.
+    pub fn suggest_rebalance(&self, target_depth: usize) -> Vec<ReportingChange> {
+        let target_depth = target_depth.max(1);
+        let mut depth: HashMap<&EntityId<Role>, usize> = HashMap::new();
+        let mut manager_of: HashMap<&EntityId<Role>, Option<&EntityId<Role>>> = HashMap::new();
+        let mut changes = Vec::new();
+
+        for role in self.roles_sorted_by_hierarchy() {
+            let manager = role.reports_to.as_ref().filter(|manager| depth.contains_key(manager));
+            let mut proposed = manager;
+            if let Some(manager) = manager {
+                if depth[manager] >= target_depth {
+                    // Climb to the ancestor on layer target_depth - 1
+                    let mut ancestor = Some(manager);
+                    for _ in 0..=depth[manager] - target_depth {
+                        ancestor = ancestor.and_then(|ancestor| manager_of[ancestor]);
+                    }
+                    proposed = ancestor;
+                    changes.push(ReportingChange {
+                        role_id: role.id.clone(),
+                        current_manager: role.reports_to.clone(),
+                        proposed_manager: proposed.cloned(),
+                    });
+                }
+            }
+            depth.insert(&role.id, proposed.map_or(1, |manager| depth[manager] + 1));
+            manager_of.insert(&role.id, proposed);
+        }
+        changes
+    }
+
+    /// Audit the structure against the default rules for this organization's type
+    pub fn validate_structure(&self) -> Vec<StructureWarning> {
+        self.validate_structure_with(&self.org_type)
+    }
+
+    /// Audit the structure against caller-supplied rules
+    pub fn validate_structure_with(&self, rules: &dyn StructureRules) -> Vec<StructureWarning> {
+        rules.check(self)
+    }
+
+    /// Department a role belongs to, directly or through its team
+    pub fn department_of(&self, role_id: &EntityId<Role>) -> Option<EntityId<Department>> {
+        let role = self.roles.get(role_id)?;
+        role.department_id.clone().or_else(|| {
+            role.team_id
+                .as_ref()
+                .and_then(|team_id| self.teams.get(team_id))
+                .and_then(|team| team.department_id.clone())
+        })
+    }
+
+    /// Permissions a role holds: its own plus its department's defaults
+    ///
+    /// Models context-granted access, e.g. every position in Finance being
+    /// able to approve budgets regardless of level. Empty for unknown roles.
+    pub fn effective_permissions(&self, role_id: &EntityId<Role>) -> HashSet<String> {
+        let Some(role) = self.roles.get(role_id) else {
+            return HashSet::new();
+        };
+
+        role.permissions
+            .iter()
+            .chain(self.department_defaults(role_id))
+            .cloned()
+            .collect()
+    }
+
+    /// Default permissions of the department a role belongs to
+    fn department_defaults(&self, role_id: &EntityId<Role>) -> &[String] {
+        self.department_of(role_id)
+            .and_then(|department_id| self.departments.get(&department_id))
+            .map(|department| department.default_permissions.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Effective permissions gained and lost if a role's own permissions are replaced
+    ///
+    /// Both lists are sorted. Department defaults still apply afterwards, so
+    /// dropping a permission the department grants loses nothing.
+    fn permission_diff(&self, role_id: &EntityId<Role>, permissions: Option<&Vec<String>>) -> (Vec<String>, Vec<String>) {
+        let Some(permissions) = permissions else {
+            return (Vec::new(), Vec::new());
+        };
+        let before = self.effective_permissions(role_id);
+        let after: HashSet<String> = permissions
+            .iter()
+            .chain(self.department_defaults(role_id))
+            .cloned()
+            .collect();
+
+        let mut gained: Vec<String> = after.difference(&before).cloned().collect();
+        let mut lost: Vec<String> = before.difference(&after).cloned().collect();
+        gained.sort();
+        lost.sort();
+        (gained, lost)
+    }
+
+    /// Non-deprecated roles in a department, including its sub-departments
+    ///
+    /// Sub-departments are followed transitively; a department is visited at
+    /// most once, so a cycle in `parent_department_id` cannot loop forever.
+    pub fn roles_in_department(&self, department_id: &EntityId<Department>) -> Vec<&Role> {
+        let mut included = HashSet::new();
+        let mut pending = vec![department_id.clone()];
+        while let Some(current) = pending.pop() {
+            if !included.insert(current.clone()) {
+                continue;
+            }
+            pending.extend(
+                self.departments
+                    .values()
+                    .filter(|dept| dept.parent_department_id.as_ref() == Some(&current))
+                    .map(|dept| dept.id.clone()),
+            );
+        }
+
+        self.roles
+            .values()
+            .filter(|role| role.status != RoleStatus::Deprecated)
+            .filter(|role| self.department_of(&role.id).is_some_and(|dept| included.contains(&dept)))
+            .collect()
+    }
+
+    /// Status as seen from outside, given the parent organization's status
+    ///
+    /// A child cannot be more operational than its parent, so the more
+    /// restrictive of the two applies: an active child of a suspended parent
+    /// is effectively suspended. The parent is a separate aggregate; pass
+    /// `None` for a root organization or when its status is unknown.
+    pub fn effective_status(&self, parent_status: Option<OrganizationStatus>) -> OrganizationStatus {
+        match parent_status {
+            Some(parent) if parent.restriction() > self.status.restriction() => parent,
+            _ => self.status.clone(),
+        }
+    }
+
     /// Get the current state of the organization for MealyStateMachine
     pub fn current_state(&self) -> OrganizationState {
         if self.organization.is_none() {
@@ -180,15 +864,365 @@ impl OrganizationAggregate {
         }
     }
 
+    /// Rename the organization
+    ///
+    /// Convenience over `UpdateOrganization`; `sibling_names` are the names of
+    /// the other children of this organization's parent, which must not be reused.
+    pub fn rename(
+        &mut self,
+        identity: MessageIdentity,
+        name: String,
+        sibling_names: Vec<String>,
+    ) -> OrganizationResult<Vec<OrganizationEvent>> {
+        let organization_id = self
+            .aggregate_id()
+            .ok_or(OrganizationError::OrganizationNotFound(self.id))?;
+
+        self.handle_command(OrganizationCommand::UpdateOrganization(UpdateOrganization {
+            identity,
+            organization_id,
+            name: Some(name),
+            display_name: None,
+            description: None,
+            status: None,
+            metadata: None,
+            sibling_names,
+        }))
+    }
+
+    /// Move a pending (or inactive, suspended or acquired) organization to Active
+    ///
+    /// Goes through `ChangeOrganizationStatus` like any other status change:
+    /// the transition is validated, and the `OrganizationStatusChanged` event
+    /// is applied and buffered as with `execute`. Prefer this over setting
+    /// `status` directly, which records nothing.
+    pub fn activate(&mut self, identity: MessageIdentity, reason: Option<String>) -> OrganizationResult<()> {
+        self.execute(OrganizationCommand::ChangeOrganizationStatus(ChangeOrganizationStatus {
+            identity,
+            organization_id: self.id,
+            new_status: OrganizationStatus::Active,
+            reason,
+            cascade_to_children: false,
+        }))
+    }
+
+    /// Create the roles of a template, wiring `reports_to` between them
+    ///
+    /// Each role goes through `CreateRole`, so the usual validation applies,
+    /// and its events are applied and buffered as with `execute`. Either every
+    /// role is created or none are. Returns the new role ids by template code.
+    pub fn seed_roles(
+        &mut self,
+        identity: &MessageIdentity,
+        templates: &[crate::role_templates::RoleTemplate],
+    ) -> OrganizationResult<HashMap<&'static str, EntityId<Role>>> {
+        let organization_id = self
+            .aggregate_id()
+            .ok_or(OrganizationError::OrganizationNotFound(self.id))?;
+
+        let mut next = self.clone();
+        let mut seeded: HashMap<&'static str, EntityId<Role>> = HashMap::new();
+        for template in templates {
+            let reports_to = match template.reports_to {
+                Some(code) => Some(seeded.get(code).cloned().ok_or_else(|| {
+                    OrganizationError::EntityNotFound(format!("Template role '{}' must precede '{}'", code, template.code))
+                })?),
+                None => None,
+            };
+            let events = next.handle_command(OrganizationCommand::CreateRole(CreateRole {
+                identity: MessageIdentity {
+                    correlation_id: identity.correlation_id.clone(),
+                    causation_id: cim_domain::CausationId(identity.message_id),
+                    message_id: Uuid::now_v7(),
+                },
+                organization_id: organization_id.clone(),
+                department_id: None,
+                team_id: None,
+                title: template.title.to_string(),
+                code: template.code.to_string(),
+                description: None,
+                role_type: template.role_type.clone(),
+                level: Some(template.level),
+                reports_to,
+                permissions: template.permissions.iter().map(|p| p.to_string()).collect(),
+                responsibilities: Vec::new(),
+            }))?;
+
+            for event in &events {
+                if let OrganizationEvent::RoleCreated(created) = event {
+                    seeded.insert(template.code, created.role_id.clone());
+                }
+                next = next.apply_event_pure(event)?;
+            }
+            next.uncommitted.extend(events);
+        }
+
+        *self = next;
+        Ok(seeded)
+    }
+
+    /// Commands that carry a cascading status change down to child organizations
+    ///
+    /// Children are separate aggregates, so a `ChangeOrganizationStatus` or
+    /// `DissolveOrganization` with `cascade_to_children` set yields the same
+    /// command for each child, for the command handler to dispatch once
+    /// `command` has been handled. The child commands keep the correlation id,
+    /// are caused by `command`, and cascade in turn. Any other command, or one
+    /// without the flag, yields nothing.
+    pub fn cascade_to_children(&self, command: &OrganizationCommand) -> Vec<OrganizationCommand> {
+        let mut child_ids: Vec<Uuid> = self.child_organizations.keys().copied().collect();
+        child_ids.sort();
+        let child_identity = |parent: &MessageIdentity| MessageIdentity {
+            correlation_id: parent.correlation_id.clone(),
+            causation_id: cim_domain::CausationId(parent.message_id),
+            message_id: Uuid::now_v7(),
+        };
+
+        match command {
+            OrganizationCommand::ChangeOrganizationStatus(cmd) if cmd.cascade_to_children => child_ids
+                .into_iter()
+                .map(|child_id| {
+                    OrganizationCommand::ChangeOrganizationStatus(ChangeOrganizationStatus {
+                        identity: child_identity(&cmd.identity),
+                        organization_id: child_id,
+                        ..cmd.clone()
+                    })
+                })
+                .collect(),
+            OrganizationCommand::DissolveOrganization(cmd) if cmd.cascade_to_children => child_ids
+                .into_iter()
+                .map(|child_id| {
+                    OrganizationCommand::DissolveOrganization(DissolveOrganization {
+                        identity: child_identity(&cmd.identity),
+                        organization_id: EntityId::from_uuid(child_id),
+                        ..cmd.clone()
+                    })
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// The command that records a merge on the merged organization
+    ///
+    /// The merged organization is a separate aggregate, so once the survivor
+    /// has handled `MergeOrganizations` the command handler dispatches the
+    /// returned `CompleteMerge` to it. The command keeps the correlation id and
+    /// is caused by `command`. Any other command yields nothing.
+    pub fn merged_party_command(&self, command: &OrganizationCommand) -> Option<OrganizationCommand> {
+        let OrganizationCommand::MergeOrganizations(cmd) = command else {
+            return None;
+        };
+        Some(OrganizationCommand::CompleteMerge(CompleteMerge {
+            identity: MessageIdentity {
+                correlation_id: cmd.identity.correlation_id.clone(),
+                causation_id: cim_domain::CausationId(cmd.identity.message_id),
+                message_id: Uuid::now_v7(),
+            },
+            merged_organization_id: cmd.merged_organization_id.clone(),
+            surviving_organization_id: cmd.surviving_organization_id.clone(),
+            merger_type: cmd.merger_type.clone(),
+        }))
+    }
+
+    /// Plan the commands that move this aggregate to `target`
+    ///
+    /// Covers roles (created, updated or deprecated) and the organization
+    /// status. Role ids are matched between the two aggregates; roles only in
+    /// `target` are created with fresh ids. Fails with `InvalidStructure` when
+    /// the difference cannot be expressed as commands, e.g. an invalid status
+    /// transition or a cleared field.
+    pub fn plan_transition(&self, target: &OrganizationAggregate) -> OrganizationResult<Vec<OrganizationCommand>> {
+        let organization_id = self
+            .aggregate_id()
+            .ok_or(OrganizationError::OrganizationNotFound(self.id))?;
+        if target.id != self.id {
+            return Err(OrganizationError::InvalidStructure(
+                format!("Cannot plan a transition from organization {} to {}", self.id, target.id)
+            ));
+        }
+
+        let plan_id = Uuid::now_v7();
+        let identity = || MessageIdentity {
+            correlation_id: cim_domain::CorrelationId::Single(plan_id),
+            causation_id: cim_domain::CausationId(plan_id),
+            message_id: Uuid::now_v7(),
+        };
+        let infeasible = |role: &Role, what: &str| {
+            OrganizationError::InvalidStructure(format!("Role {} cannot {} through commands", role.code, what))
+        };
+
+        let mut role_commands = Vec::new();
+
+        let mut target_roles: Vec<&Role> = target.roles.values().collect();
+        target_roles.sort_by_key(|role| (role.created_at, role.id.to_string()));
+        for wanted in target_roles {
+            let Some(current) = self.roles.get(&wanted.id) else {
+                if wanted.status != RoleStatus::Active {
+                    return Err(infeasible(wanted, "be created in a non-active status"));
+                }
+                if wanted.reports_to.as_ref().is_some_and(|manager| !self.roles.contains_key(manager)) {
+                    return Err(infeasible(wanted, "report to a role created in the same plan"));
+                }
+                role_commands.push(OrganizationCommand::CreateRole(CreateRole {
+                    identity: identity(),
+                    organization_id: organization_id.clone(),
+                    department_id: wanted.department_id.clone(),
+                    team_id: wanted.team_id.clone(),
+                    title: wanted.title.clone(),
+                    code: wanted.code.clone(),
+                    description: wanted.description.clone(),
+                    role_type: wanted.role_type.clone(),
+                    level: wanted.level,
+                    reports_to: wanted.reports_to.clone(),
+                    permissions: wanted.permissions.clone(),
+                    responsibilities: wanted.responsibilities.clone(),
+                }));
+                continue;
+            };
+
+            if current.department_id != wanted.department_id
+                || current.team_id != wanted.team_id
+                || current.code != wanted.code
+            {
+                return Err(infeasible(wanted, "move department, team or code"));
+            }
+            if (current.description.is_some() && wanted.description.is_none())
+                || (current.level.is_some() && wanted.level.is_none())
+                || (current.reports_to.is_some() && wanted.reports_to.is_none())
+            {
+                return Err(infeasible(wanted, "clear a field"));
+            }
+            if current.status == RoleStatus::Deprecated && wanted.status != RoleStatus::Deprecated {
+                return Err(infeasible(wanted, "be restored from deprecation"));
+            }
+
+            let update = UpdateRole {
+                identity: identity(),
+                role_id: wanted.id.clone(),
+                organization_id: organization_id.clone(),
+                title: changed(&current.title, &wanted.title),
+                description: changed(&current.description, &wanted.description).flatten(),
+                role_type: changed(&current.role_type, &wanted.role_type),
+                level: changed(&current.level, &wanted.level).flatten(),
+                reports_to: changed(&current.reports_to, &wanted.reports_to).flatten(),
+                permissions: changed(&current.permissions, &wanted.permissions),
+                responsibilities: changed(&current.responsibilities, &wanted.responsibilities),
+                status: changed(&current.status, &wanted.status)
+                    .filter(|status| *status != RoleStatus::Deprecated),
+            };
+            let has_changes = update.title.is_some()
+                || update.description.is_some()
+                || update.role_type.is_some()
+                || update.level.is_some()
+                || update.reports_to.is_some()
+                || update.permissions.is_some()
+                || update.responsibilities.is_some()
+                || update.status.is_some();
+            if has_changes {
+                role_commands.push(OrganizationCommand::UpdateRole(update));
+            }
+
+            if wanted.status == RoleStatus::Deprecated && current.status != RoleStatus::Deprecated {
+                role_commands.push(self.plan_deprecate_role(identity(), &organization_id, wanted));
+            }
+        }
+
+        let mut removed_roles: Vec<&Role> = self
+            .roles
+            .values()
+            .filter(|role| role.status != RoleStatus::Deprecated && !target.roles.contains_key(&role.id))
+            .collect();
+        removed_roles.sort_by_key(|role| (role.created_at, role.id.to_string()));
+        for role in removed_roles {
+            role_commands.push(self.plan_deprecate_role(identity(), &organization_id, role));
+        }
+
+        let mut commands = Vec::new();
+        let status_change = if target.status != self.status {
+            if !self.is_valid_status_transition(self.status.clone(), target.status.clone()) {
+                return Err(OrganizationError::InvalidStructure(
+                    format!("Invalid status transition from {:?} to {:?}", self.status, target.status)
+                ));
+            }
+            Some(OrganizationCommand::ChangeOrganizationStatus(ChangeOrganizationStatus {
+                identity: identity(),
+                organization_id: self.id,
+                new_status: target.status.clone(),
+                reason: None,
+                cascade_to_children: false,
+            }))
+        } else {
+            None
+        };
+
+        // Activate before restructuring; any other status change comes last
+        if target.status == OrganizationStatus::Active {
+            commands.extend(status_change);
+            commands.extend(role_commands);
+        } else {
+            commands.extend(role_commands);
+            commands.extend(status_change);
+        }
+        Ok(commands)
+    }
+
+    fn plan_deprecate_role(
+        &self,
+        identity: MessageIdentity,
+        organization_id: &EntityId<Organization>,
+        role: &Role,
+    ) -> OrganizationCommand {
+        OrganizationCommand::DeprecateRole(DeprecateRole {
+            identity,
+            role_id: role.id.clone(),
+            organization_id: organization_id.clone(),
+            reason: "Removed by planned transition".to_string(),
+            replacement_role_id: None,
+            effective_date: Utc::now(),
+        })
+    }
+
+    /// Handle a command, apply its events and buffer them for persistence
+    ///
+    /// Unlike `handle_command`, the caller does not thread events back into
+    /// the aggregate. Either all of the command's events are applied or none
+    /// are. Collect the buffered events with `take_uncommitted`.
+    pub fn execute(&mut self, command: OrganizationCommand) -> OrganizationResult<()> {
+        let events = self.handle_command(command)?;
+
+        let mut next = self.clone();
+        for event in &events {
+            next = next.apply_event_pure(event)?;
+        }
+        next.uncommitted.extend(events);
+        *self = next;
+        Ok(())
+    }
+
+    /// Events applied by `execute` since the last call, in order
+    pub fn uncommitted_events(&self) -> &[OrganizationEvent] {
+        &self.uncommitted
+    }
+
+    /// Drain the buffered events, typically to hand them to the repository
+    pub fn take_uncommitted(&mut self) -> Vec<OrganizationEvent> {
+        std::mem::take(&mut self.uncommitted)
+    }
+
     /// Handle organization commands
     /// NOTE: This only handles pure organization domain commands.
     /// Relationship commands (person-to-role, facility-to-location) are handled in separate Association domain.
     pub fn handle_command(&mut self, command: OrganizationCommand) -> OrganizationResult<Vec<OrganizationEvent>> {
+        self.validate_status_allows(&command)?;
+
         match command {
             OrganizationCommand::CreateOrganization(cmd) => self.handle_create_organization(cmd),
             OrganizationCommand::UpdateOrganization(cmd) => self.handle_update_organization(cmd),
+            OrganizationCommand::UpdateOrganizationMetadata(cmd) => self.handle_update_organization_metadata(cmd),
             OrganizationCommand::DissolveOrganization(cmd) => self.handle_dissolve_organization(cmd),
             OrganizationCommand::MergeOrganizations(cmd) => self.handle_merge_organizations(cmd),
+            OrganizationCommand::CompleteMerge(cmd) => self.handle_complete_merge(cmd),
             OrganizationCommand::ChangeOrganizationStatus(cmd) => self.handle_change_organization_status(cmd),
             OrganizationCommand::CreateDepartment(cmd) => self.handle_create_department(cmd),
             OrganizationCommand::UpdateDepartment(cmd) => self.handle_update_department(cmd),
@@ -200,11 +1234,52 @@ impl OrganizationAggregate {
             OrganizationCommand::CreateRole(cmd) => self.handle_create_role(cmd),
             OrganizationCommand::UpdateRole(cmd) => self.handle_update_role(cmd),
             OrganizationCommand::DeprecateRole(cmd) => self.handle_deprecate_role(cmd),
+            OrganizationCommand::SwapReporting(cmd) => self.handle_swap_reporting(cmd),
+            OrganizationCommand::RenameRoleTitle(cmd) => self.handle_rename_role_title(cmd),
             OrganizationCommand::CreateFacility(cmd) => self.handle_create_facility(cmd),
             OrganizationCommand::UpdateFacility(cmd) => self.handle_update_facility(cmd),
             OrganizationCommand::RemoveFacility(cmd) => self.handle_remove_facility(cmd),
             OrganizationCommand::AddChildOrganization(cmd) => self.handle_add_child_organization(cmd),
             OrganizationCommand::RemoveChildOrganization(cmd) => self.handle_remove_child_organization(cmd),
+            OrganizationCommand::AddOrganizationTag(cmd) => self.handle_add_organization_tag(cmd),
+            OrganizationCommand::RemoveOrganizationTag(cmd) => self.handle_remove_organization_tag(cmd),
+        }
+    }
+
+    /// Validate a command without executing it
+    ///
+    /// Runs the same checks the command handlers run, but emits no events and
+    /// leaves the aggregate untouched, so callers can reject bad requests up front.
+    pub fn validate_command(&self, command: &OrganizationCommand) -> OrganizationResult<()> {
+        self.validate_status_allows(command)?;
+
+        match command {
+            OrganizationCommand::CreateOrganization(cmd) => self.validate_create_organization(cmd),
+            OrganizationCommand::UpdateOrganization(cmd) => self.validate_update_organization(cmd),
+            OrganizationCommand::UpdateOrganizationMetadata(cmd) => self.validate_update_organization_metadata(cmd),
+            OrganizationCommand::DissolveOrganization(cmd) => self.validate_dissolve_organization(cmd),
+            OrganizationCommand::MergeOrganizations(cmd) => self.validate_merge_organizations(cmd),
+            OrganizationCommand::CompleteMerge(cmd) => self.validate_complete_merge(cmd),
+            OrganizationCommand::ChangeOrganizationStatus(cmd) => self.validate_change_organization_status(cmd),
+            OrganizationCommand::CreateDepartment(cmd) => self.validate_create_department(cmd),
+            OrganizationCommand::UpdateDepartment(cmd) => self.validate_department_exists(&cmd.department_id),
+            OrganizationCommand::RestructureDepartment(cmd) => self.validate_department_exists(&cmd.department_id),
+            OrganizationCommand::DissolveDepartment(cmd) => self.validate_dissolve_department(cmd),
+            OrganizationCommand::CreateTeam(cmd) => self.validate_create_team(cmd),
+            OrganizationCommand::UpdateTeam(cmd) => self.validate_team_exists(&cmd.team_id),
+            OrganizationCommand::DisbandTeam(cmd) => self.validate_disband_team(cmd),
+            OrganizationCommand::CreateRole(cmd) => self.validate_create_role(cmd),
+            OrganizationCommand::UpdateRole(cmd) => self.validate_update_role(cmd),
+            OrganizationCommand::DeprecateRole(_) => Ok(()),
+            OrganizationCommand::SwapReporting(cmd) => self.validate_swap_reporting(cmd),
+            OrganizationCommand::RenameRoleTitle(cmd) => self.validate_rename_role_title(cmd),
+            OrganizationCommand::CreateFacility(_) => Ok(()),
+            OrganizationCommand::UpdateFacility(cmd) => self.validate_facility_exists(&cmd.facility_id),
+            OrganizationCommand::RemoveFacility(cmd) => self.validate_facility_exists(&cmd.facility_id),
+            OrganizationCommand::AddChildOrganization(cmd) => self.validate_add_child_organization(cmd),
+            OrganizationCommand::RemoveChildOrganization(cmd) => self.validate_remove_child_organization(cmd),
+            OrganizationCommand::AddOrganizationTag(cmd) => self.validate_add_organization_tag(cmd),
+            OrganizationCommand::RemoveOrganizationTag(cmd) => self.validate_remove_organization_tag(cmd),
         }
     }
 
@@ -221,15 +1296,22 @@ impl OrganizationAggregate {
                     parent_id: e.parent_id.clone(),
                     organization_type: e.organization_type.clone(),
                     status: OrganizationStatus::Active,
-                    founded_date: None,
+                    founded_date: e.founded_date,
                     metadata: e.metadata.clone(),
                     created_at: e.occurred_at,
                     updated_at: e.occurred_at,
                 };
+                new_aggregate.id = e.organization_id.clone().into();
+                new_aggregate.name = e.name.clone();
+                new_aggregate.org_type = e.organization_type.clone();
                 new_aggregate.organization = Some(org);
                 new_aggregate.status = OrganizationStatus::Active;
+                new_aggregate.created_at = Some(e.occurred_at);
             }
             OrganizationEvent::OrganizationUpdated(e) => {
+                if let Some(name) = &e.changes.name {
+                    new_aggregate.name = name.clone();
+                }
                 if let Some(org) = &mut new_aggregate.organization {
                     if let Some(name) = &e.changes.name {
                         org.name = name.clone();
@@ -246,6 +1328,12 @@ impl OrganizationAggregate {
                     org.updated_at = e.occurred_at;
                 }
             }
+            OrganizationEvent::OrganizationMetadataUpdated(e) => {
+                if let Some(org) = &mut new_aggregate.organization {
+                    merge_patch(&mut org.metadata, &e.patch);
+                    org.updated_at = e.occurred_at;
+                }
+            }
             OrganizationEvent::DepartmentCreated(e) => {
                 let dept = Department {
                     id: e.department_id.clone(),
@@ -254,13 +1342,73 @@ impl OrganizationAggregate {
                     name: e.name.clone(),
                     code: e.code.clone(),
                     description: None,
-                    head_role_id: None,
+                    head_role_id: e.head_role_id.clone(),
+                    default_permissions: e.default_permissions.clone(),
                     status: DepartmentStatus::Active,
                     created_at: e.occurred_at,
                     updated_at: e.occurred_at,
                 };
                 new_aggregate.departments.insert(e.department_id.clone(), dept);
             }
+            OrganizationEvent::DepartmentUpdated(e) => {
+                if let Some(dept) = new_aggregate.departments.get_mut(&e.department_id) {
+                    if let Some(name) = &e.changes.name {
+                        dept.name = name.clone();
+                    }
+                    if let Some(code) = &e.changes.code {
+                        dept.code = code.clone();
+                    }
+                    if let Some(description) = &e.changes.description {
+                        dept.description = Some(description.clone());
+                    }
+                    if let Some(head_role_id) = &e.changes.head_role_id {
+                        dept.head_role_id = Some(head_role_id.clone());
+                    }
+                    if let Some(status) = &e.changes.status {
+                        dept.status = status.clone();
+                    }
+                    dept.updated_at = e.occurred_at;
+                }
+            }
+            OrganizationEvent::DepartmentRestructured(e) => {
+                if let Some(dept) = new_aggregate.departments.get_mut(&e.department_id) {
+                    dept.parent_department_id = e.new_parent_id.clone();
+                    dept.updated_at = e.occurred_at;
+                }
+            }
+            OrganizationEvent::DepartmentDissolved(e) => {
+                let mut grandparent = None;
+                if let Some(dept) = new_aggregate.departments.get_mut(&e.department_id) {
+                    dept.status = DepartmentStatus::Dissolved;
+                    dept.updated_at = e.occurred_at;
+                    grandparent = dept.parent_department_id.clone();
+                }
+                // Sub-departments move under the receiving department; if that
+                // is one of them, it takes the dissolved department's place
+                for dept in new_aggregate.departments.values_mut() {
+                    if dept.parent_department_id.as_ref() == Some(&e.department_id) {
+                        dept.parent_department_id = if e.transfer_to.as_ref() == Some(&dept.id) {
+                            grandparent.clone()
+                        } else {
+                            e.transfer_to.clone()
+                        };
+                        dept.updated_at = e.occurred_at;
+                    }
+                }
+                // Teams and roles move to the receiving department, if any
+                for team in new_aggregate.teams.values_mut() {
+                    if team.department_id.as_ref() == Some(&e.department_id) {
+                        team.department_id = e.transfer_to.clone();
+                        team.updated_at = e.occurred_at;
+                    }
+                }
+                for role in new_aggregate.roles.values_mut() {
+                    if role.department_id.as_ref() == Some(&e.department_id) {
+                        role.department_id = e.transfer_to.clone();
+                        role.updated_at = e.occurred_at;
+                    }
+                }
+            }
             OrganizationEvent::TeamFormed(e) => {
                 let team = Team {
                     id: e.team_id.clone(),
@@ -277,6 +1425,39 @@ impl OrganizationAggregate {
                 };
                 new_aggregate.teams.insert(e.team_id.clone(), team);
             }
+            OrganizationEvent::TeamUpdated(e) => {
+                if let Some(team) = new_aggregate.teams.get_mut(&e.team_id) {
+                    if let Some(name) = &e.changes.name {
+                        team.name = name.clone();
+                    }
+                    if let Some(description) = &e.changes.description {
+                        team.description = Some(description.clone());
+                    }
+                    if let Some(lead_role_id) = &e.changes.lead_role_id {
+                        team.lead_role_id = Some(lead_role_id.clone());
+                    }
+                    if let Some(max_members) = e.changes.max_members {
+                        team.max_members = Some(max_members);
+                    }
+                    if let Some(status) = &e.changes.status {
+                        team.status = status.clone();
+                    }
+                    team.updated_at = e.occurred_at;
+                }
+            }
+            OrganizationEvent::TeamDisbanded(e) => {
+                if let Some(team) = new_aggregate.teams.get_mut(&e.team_id) {
+                    team.status = TeamStatus::Disbanded;
+                    team.updated_at = e.occurred_at;
+                }
+                // Roles move to the receiving team, if any
+                for role in new_aggregate.roles.values_mut() {
+                    if role.team_id.as_ref() == Some(&e.team_id) {
+                        role.team_id = e.members_transferred_to.clone();
+                        role.updated_at = e.occurred_at;
+                    }
+                }
+            }
             OrganizationEvent::RoleCreated(e) => {
                 let role = Role {
                     id: e.role_id.clone(),
@@ -297,6 +1478,41 @@ impl OrganizationAggregate {
                 };
                 new_aggregate.roles.insert(e.role_id.clone(), role);
             }
+            OrganizationEvent::RoleUpdated(e) => {
+                if let Some(role) = new_aggregate.roles.get_mut(&e.role_id) {
+                    if let Some(title) = &e.changes.title {
+                        role.title = title.clone();
+                    }
+                    if let Some(description) = &e.changes.description {
+                        role.description = Some(description.clone());
+                    }
+                    if let Some(role_type) = &e.changes.role_type {
+                        role.role_type = role_type.clone();
+                    }
+                    if let Some(level) = e.changes.level {
+                        role.level = Some(level);
+                    }
+                    if let Some(reports_to) = &e.changes.reports_to {
+                        role.reports_to = Some(reports_to.clone());
+                    }
+                    if let Some(permissions) = &e.changes.permissions {
+                        role.permissions = permissions.clone();
+                    }
+                    if let Some(responsibilities) = &e.changes.responsibilities {
+                        role.responsibilities = responsibilities.clone();
+                    }
+                    if let Some(status) = &e.changes.status {
+                        role.status = status.clone();
+                    }
+                    role.updated_at = e.occurred_at;
+                }
+            }
+            OrganizationEvent::RoleDeprecated(e) => {
+                if let Some(role) = new_aggregate.roles.get_mut(&e.role_id) {
+                    role.status = RoleStatus::Deprecated;
+                    role.updated_at = e.occurred_at;
+                }
+            }
             OrganizationEvent::FacilityCreated(e) => {
                 let facility = Facility {
                     id: e.facility_id.clone(),
@@ -343,54 +1559,615 @@ impl OrganizationAggregate {
                 new_aggregate.status = e.new_status.clone();
                 if let Some(org) = &mut new_aggregate.organization {
                     org.status = e.new_status.clone();
+                    if let Some(parent_id) = &e.parent_id {
+                        org.parent_id = Some(parent_id.clone());
+                    }
+                }
+            }
+            OrganizationEvent::OrganizationDissolved(_e) => {
+                new_aggregate.status = OrganizationStatus::Dissolved;
+                if let Some(org) = &mut new_aggregate.organization {
+                    org.status = OrganizationStatus::Dissolved;
+                }
+            }
+            OrganizationEvent::OrganizationMerged(e) => {
+                // Recorded by the survivor, which keeps its status and, after an
+                // acquisition, gains the child. The merged organization records
+                // its own status change through `CompleteMerge`.
+                let merged_id: Uuid = e.merged_organization_id.clone().into();
+                let surviving_id: Uuid = e.surviving_organization_id.clone().into();
+                if new_aggregate.id == surviving_id && matches!(e.merger_type, MergerType::Acquisition) {
+                    // Events recorded before the merged organization's name and
+                    // type were carried fall back to its id and an unknown type
+                    let child = ChildOrganization {
+                        id: merged_id,
+                        name: e.merged_organization_name.clone().unwrap_or_else(|| merged_id.to_string()),
+                        org_type: e
+                            .merged_organization_type
+                            .clone()
+                            .unwrap_or_else(|| OrganizationType::Other("Unknown".to_string())),
+                        added_at: e.occurred_at,
+                    };
+                    new_aggregate.child_organizations.insert(merged_id, child);
+                }
+            }
+            OrganizationEvent::ChildOrganizationAdded(e) => {
+                let child = ChildOrganization {
+                    id: e.child_organization_id,
+                    name: e.child_name.clone(),
+                    org_type: e.child_type.clone(),
+                    added_at: e.occurred_at,
+                };
+                new_aggregate.child_organizations.insert(e.child_organization_id, child);
+            }
+            OrganizationEvent::ChildOrganizationRemoved(e) => {
+                new_aggregate.child_organizations.remove(&e.child_organization_id);
+            }
+            OrganizationEvent::OrganizationTagAdded(e) => {
+                new_aggregate.tags.insert(e.tag.clone());
+            }
+            OrganizationEvent::OrganizationTagRemoved(e) => {
+                new_aggregate.tags.remove(&e.tag);
+            }
+        }
+
+        new_aggregate.version += 1;
+        new_aggregate.updated_at = Some(event.occurred_at());
+        Ok(new_aggregate)
+    }
+
+    /// Apply an event to update aggregate state (mutable wrapper for compatibility)
+    /// This is a compatibility wrapper - prefer `apply_event_pure` for pure functional approach
+    pub fn apply_event(&mut self, event: &OrganizationEvent) -> OrganizationResult<()> {
+        let new_aggregate = self.apply_event_pure(event)?;
+        *self = new_aggregate;
+        Ok(())
+    }
+
+    /// Rebuild the aggregate as it was after the first `version` events
+    ///
+    /// `events` is the full stream for one organization, starting with its
+    /// `OrganizationCreated` event. Fails if `version` is past the end of it.
+    pub fn replay_until(events: &[OrganizationEvent], version: u64) -> OrganizationResult<Self> {
+        let available = events.len() as u64;
+        if version > available {
+            return Err(OrganizationError::VersionOutOfRange { requested: version, available });
+        }
+
+        let events = &events[..version as usize];
+        let role_count = events
+            .iter()
+            .filter(|event| matches!(event, OrganizationEvent::RoleCreated(_)))
+            .count();
+
+        let mut aggregate = Self::empty();
+        aggregate.roles.reserve(role_count);
+        for event in events {
+            aggregate.apply_event(event)?;
+        }
+        Ok(aggregate)
+    }
+
+    /// Drop history that cancels itself out
+    ///
+    /// A child organization, tag or facility that is added and later removed
+    /// within `events` leaves no trace in the final state, so the add, the
+    /// remove and any updates in between are dropped. Replaying the result
+    /// yields a semantically equal aggregate (see `semantically_eq`); `version`
+    /// and `updated_at` differ, since the dropped events no longer count.
+    ///
+    /// Intended for snapshot and archival pipelines only. Live streams must
+    /// keep every event, since subscribers and sequence checks rely on them.
+    pub fn compact_events(events: &[OrganizationEvent]) -> Vec<OrganizationEvent> {
+        let mut open: HashMap<String, Vec<usize>> = HashMap::new();
+        let mut dropped = vec![false; events.len()];
+
+        for (index, event) in events.iter().enumerate() {
+            let Some((key, step)) = lifecycle_step(event) else {
+                continue;
+            };
+            match step {
+                LifecycleStep::Open => {
+                    open.insert(key, vec![index]);
+                }
+                LifecycleStep::Touch => {
+                    if let Some(indices) = open.get_mut(&key) {
+                        indices.push(index);
+                    }
+                }
+                LifecycleStep::Close => {
+                    if let Some(indices) = open.remove(&key) {
+                        for earlier in indices {
+                            dropped[earlier] = true;
+                        }
+                        dropped[index] = true;
+                    }
                 }
             }
-            OrganizationEvent::OrganizationDissolved(_e) => {
-                new_aggregate.status = OrganizationStatus::Dissolved;
-                if let Some(org) = &mut new_aggregate.organization {
-                    org.status = OrganizationStatus::Dissolved;
-                }
+        }
+
+        events
+            .iter()
+            .zip(dropped)
+            .filter(|(_, dropped)| !dropped)
+            .map(|(event, _)| event.clone())
+            .collect()
+    }
+
+    /// Apply an event only if it is the next one in the aggregate's sequence
+    ///
+    /// Used when replaying a snapshot plus subsequent events: any sequence other
+    /// than `version + 1` indicates a gap or a duplicate in the stream.
+    pub fn apply_event_checked(&mut self, event: &OrganizationEvent, expected_sequence: u64) -> OrganizationResult<()> {
+        let next = self.version + 1;
+        if expected_sequence != next {
+            return Err(OrganizationError::EventOutOfSequence {
+                expected: next,
+                actual: expected_sequence,
+            });
+        }
+        self.apply_event(event)
+    }
+
+    // Validation shared by command handlers and `validate_command`
+
+    fn validate_status_allows(&self, command: &OrganizationCommand) -> OrganizationResult<()> {
+        if !self.status.allows_command(command) {
+            return Err(OrganizationError::InvalidStatus(
+                format!("{} is not allowed while the organization is {:?}", command.command_type(), self.status)
+            ));
+        }
+        Ok(())
+    }
+
+    fn validate_create_organization(&self, cmd: &CreateOrganization) -> OrganizationResult<()> {
+        if self.organization.is_some() {
+            return Err(OrganizationError::DuplicateEntity("Organization already exists".to_string()));
+        }
+
+        if let Some(founded_date) = cmd.founded_date {
+            if founded_date > Utc::now() {
+                return Err(OrganizationError::InvalidStructure(
+                    format!("Founded date {} is in the future", founded_date)
+                ));
+            }
+        }
+        validate_metadata_tax_id(&cmd.organization_type, &cmd.metadata)
+    }
+
+    fn validate_update_organization(&self, cmd: &UpdateOrganization) -> OrganizationResult<()> {
+        self.validate_organization_exists(&cmd.organization_id)?;
+
+        let has_parent = self.organization.as_ref().is_some_and(|org| org.parent_id.is_some());
+        if let Some(name) = cmd.name.as_ref().filter(|_| has_parent) {
+            if cmd.sibling_names.iter().any(|sibling| sibling.eq_ignore_ascii_case(name)) {
+                return Err(OrganizationError::DuplicateEntity(
+                    format!("A sibling organization is already named '{}'", name)
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_update_organization_metadata(&self, cmd: &UpdateOrganizationMetadata) -> OrganizationResult<()> {
+        self.validate_organization_exists(&cmd.organization_id)?;
+
+        if !cmd.patch.is_object() {
+            return Err(OrganizationError::InvalidStructure(
+                "Metadata patch must be a JSON object".to_string()
+            ));
+        }
+
+        validate_metadata_tax_id(&self.org_type, &cmd.patch)
+    }
+
+    fn validate_dissolve_organization(&self, cmd: &DissolveOrganization) -> OrganizationResult<()> {
+        self.validate_organization_exists(&cmd.organization_id)
+    }
+
+    fn validate_merge_organizations(&self, cmd: &MergeOrganizations) -> OrganizationResult<()> {
+        self.validate_organization_exists(&cmd.surviving_organization_id)?;
+
+        // Check for self-merge
+        if cmd.surviving_organization_id == cmd.merged_organization_id {
+            return Err(OrganizationError::CircularReference("Organization cannot merge with itself".to_string()));
+        }
+
+        if self.id != Uuid::from(cmd.surviving_organization_id.clone()) {
+            return Err(OrganizationError::InvalidMerge(format!(
+                "Organization {} is not the surviving party; the merged party takes CompleteMerge",
+                self.id
+            )));
+        }
+
+        if let Some(counterpart) = &cmd.counterpart {
+            self.validate_merge_compatibility(counterpart)?;
+        }
+        Ok(())
+    }
+
+    fn validate_complete_merge(&self, cmd: &CompleteMerge) -> OrganizationResult<()> {
+        self.validate_organization_exists(&cmd.merged_organization_id)?;
+
+        if cmd.surviving_organization_id == cmd.merged_organization_id {
+            return Err(OrganizationError::CircularReference("Organization cannot merge with itself".to_string()));
+        }
+        if !self.is_merged_party(cmd) {
+            return Err(OrganizationError::InvalidMerge(format!(
+                "Organization {} is not the merged party",
+                self.id
+            )));
+        }
+        if !matches!(self.status, OrganizationStatus::Active | OrganizationStatus::Inactive) {
+            return Err(OrganizationError::InvalidMerge(
+                format!("Organization {} is {:?}", self.id, self.status)
+            ));
+        }
+        Ok(())
+    }
+
+    /// Whether this aggregate is the organization absorbed by the merge
+    fn is_merged_party(&self, cmd: &CompleteMerge) -> bool {
+        self.id == Uuid::from(cmd.merged_organization_id.clone())
+    }
+
+    /// Both parties must be operating and of compatible types
+    fn validate_merge_compatibility(&self, counterpart: &MergeCounterpart) -> OrganizationResult<()> {
+        let mergeable = |status: &OrganizationStatus| {
+            matches!(status, OrganizationStatus::Active | OrganizationStatus::Inactive)
+        };
+        if !mergeable(&self.status) {
+            return Err(OrganizationError::InvalidMerge(
+                format!("Organization {} is {:?}", self.id, self.status)
+            ));
+        }
+        if !mergeable(&counterpart.status) {
+            return Err(OrganizationError::InvalidMerge(
+                format!("Other organization is {:?}", counterpart.status)
+            ));
+        }
+        if !self.org_type.can_merge_with(&counterpart.org_type) {
+            return Err(OrganizationError::InvalidMerge(format!(
+                "{:?} cannot merge with {:?}",
+                self.org_type, counterpart.org_type
+            )));
+        }
+        Ok(())
+    }
+
+    fn validate_change_organization_status(&self, cmd: &ChangeOrganizationStatus) -> OrganizationResult<()> {
+        if !self.is_valid_status_transition(self.status.clone(), cmd.new_status.clone()) {
+            return Err(OrganizationError::InvalidStructure(
+                format!("Invalid status transition from {:?} to {:?}", self.status, cmd.new_status)
+            ));
+        }
+        Ok(())
+    }
+
+    fn validate_create_department(&self, cmd: &CreateDepartment) -> OrganizationResult<()> {
+        self.validate_organization_exists(&cmd.organization_id)
+    }
+
+    fn validate_create_team(&self, cmd: &CreateTeam) -> OrganizationResult<()> {
+        self.validate_organization_exists(&cmd.organization_id)?;
+
+        // Teams can only be formed under a department that is still operating
+        if let Some(department_id) = &cmd.department_id {
+            self.validate_department_exists(department_id)?;
+            let status = &self.departments[department_id].status;
+            if *status != DepartmentStatus::Active {
+                return Err(OrganizationError::InvalidStructure(format!(
+                    "Cannot form a team under department {} with status {:?}",
+                    department_id, status
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_dissolve_department(&self, cmd: &DissolveDepartment) -> OrganizationResult<()> {
+        self.validate_department_exists(&cmd.department_id)?;
+
+        let Some(transfer_to) = &cmd.transfer_to else {
+            return Ok(());
+        };
+        if *transfer_to == cmd.department_id {
+            return Err(OrganizationError::InvalidStructure(format!(
+                "Department {} cannot take over its own teams and roles",
+                transfer_to
+            )));
+        }
+        let receiving = self.departments.get(transfer_to).ok_or_else(|| {
+            OrganizationError::EntityNotFound(format!("Department {} not found", transfer_to))
+        })?;
+        if receiving.status == DepartmentStatus::Dissolved {
+            return Err(OrganizationError::InvalidStructure(format!(
+                "Cannot transfer to dissolved department {}",
+                transfer_to
+            )));
+        }
+
+        // A direct sub-department takes the dissolved department's place, but
+        // one further down would end up as its own ancestor's parent
+        let mut visited = HashSet::new();
+        let mut ancestor = receiving.parent_department_id.as_ref().and_then(|id| self.departments.get(id));
+        while let Some(dept) = ancestor {
+            if !visited.insert(dept.id.clone()) {
+                break;
+            }
+            if dept.parent_department_id.as_ref() == Some(&cmd.department_id) {
+                return Err(OrganizationError::InvalidStructure(format!(
+                    "Cannot transfer to department {}, which sits below sub-department {} of department {}",
+                    transfer_to, dept.id, cmd.department_id
+                )));
             }
-            OrganizationEvent::OrganizationMerged(_e) => {
-                new_aggregate.status = OrganizationStatus::Merged;
-                if let Some(org) = &mut new_aggregate.organization {
-                    org.status = OrganizationStatus::Merged;
-                }
+            ancestor = dept.parent_department_id.as_ref().and_then(|id| self.departments.get(id));
+        }
+        Ok(())
+    }
+
+    fn validate_disband_team(&self, cmd: &DisbandTeam) -> OrganizationResult<()> {
+        self.validate_team_exists(&cmd.team_id)?;
+
+        let Some(transfer_to) = &cmd.members_transfer_to else {
+            return Ok(());
+        };
+        if *transfer_to == cmd.team_id {
+            return Err(OrganizationError::InvalidStructure(format!(
+                "Team {} cannot take over its own members",
+                transfer_to
+            )));
+        }
+        let receiving = self.teams.get(transfer_to).ok_or_else(|| {
+            OrganizationError::EntityNotFound(format!("Team {} not found", transfer_to))
+        })?;
+        if receiving.status != TeamStatus::Active {
+            return Err(OrganizationError::InvalidStructure(format!(
+                "Cannot transfer members to team {} with status {:?}",
+                transfer_to, receiving.status
+            )));
+        }
+        Ok(())
+    }
+
+    fn validate_create_role(&self, cmd: &CreateRole) -> OrganizationResult<()> {
+        self.validate_organization_exists(&cmd.organization_id)?;
+
+        if let Some(manager_id) = &cmd.reports_to {
+            self.validate_manager_exists(manager_id)?;
+            self.validate_reporting_depth(None, manager_id)?;
+        }
+        if cmd.role_type == RoleType::Executive {
+            self.validate_executive_capacity()?;
+        }
+        Ok(())
+    }
+
+    fn validate_update_role(&self, cmd: &UpdateRole) -> OrganizationResult<()> {
+        self.validate_organization_exists(&cmd.organization_id)?;
+        let role = self.validate_role_exists(&cmd.role_id)?;
+
+        if let Some(manager_id) = &cmd.reports_to {
+            self.validate_manager_exists(manager_id)?;
+
+            // Check the graph as it would be once the new manager is set
+            let mut projected = self.clone();
+            if let Some(role) = projected.roles.get_mut(&cmd.role_id) {
+                role.reports_to = Some(manager_id.clone());
             }
-            OrganizationEvent::ChildOrganizationAdded(e) => {
-                let child = ChildOrganization {
-                    id: e.child_organization_id,
-                    name: e.child_name.clone(),
-                    org_type: e.child_type.clone(),
-                    added_at: e.occurred_at,
-                };
-                new_aggregate.child_organizations.insert(e.child_organization_id, child);
+            projected.validate_reporting_acyclic()?;
+
+            self.validate_reporting_depth(Some(&cmd.role_id), manager_id)?;
+        }
+
+        let is_executive = |role_type: &RoleType, status: &RoleStatus| {
+            *role_type == RoleType::Executive && *status != RoleStatus::Deprecated
+        };
+        let was_executive = is_executive(&role.role_type, &role.status);
+        let becomes_executive = is_executive(
+            cmd.role_type.as_ref().unwrap_or(&role.role_type),
+            cmd.status.as_ref().unwrap_or(&role.status),
+        );
+
+        if becomes_executive && !was_executive {
+            self.validate_executive_capacity()?;
+        }
+        Ok(())
+    }
+
+    fn validate_swap_reporting(&self, cmd: &SwapReporting) -> OrganizationResult<()> {
+        self.validate_organization_exists(&cmd.organization_id)?;
+        self.validate_role_exists(&cmd.manager_a)?;
+        self.validate_role_exists(&cmd.manager_b)?;
+        if cmd.manager_a == cmd.manager_b {
+            return Err(OrganizationError::InvalidStructure(
+                "Cannot swap a manager's reports with itself".to_string()
+            ));
+        }
+
+        let mut end_state = self.clone();
+        for event in self.swap_reporting_events(cmd) {
+            end_state = end_state.apply_event_pure(&event)?;
+        }
+        for role_id in [&cmd.manager_a, &cmd.manager_b] {
+            if let Some(cycle) = end_state.reporting_cycle(role_id) {
+                return Err(OrganizationError::CircularReference(format!(
+                    "Swap would create a reporting cycle: {}",
+                    format_role_ids(&cycle)
+                )));
             }
-            OrganizationEvent::ChildOrganizationRemoved(e) => {
-                new_aggregate.child_organizations.remove(&e.child_organization_id);
+        }
+        if let Some(max_depth) = self.max_reporting_depth {
+            if end_state.reporting_depth() > max_depth {
+                return Err(OrganizationError::InvalidStructure(
+                    format!("Swap would exceed the reporting depth cap of {}", max_depth)
+                ));
             }
-            // Handle other events...
-            _ => {}
         }
+        Ok(())
+    }
 
-        new_aggregate.version += 1;
-        Ok(new_aggregate)
+    fn validate_rename_role_title(&self, cmd: &RenameRoleTitle) -> OrganizationResult<()> {
+        self.validate_organization_exists(&cmd.organization_id)?;
+
+        let new_title = cmd.new_title.trim();
+        if new_title.is_empty() {
+            return Err(OrganizationError::InvalidStructure("Role title cannot be empty".to_string()));
+        }
+        if new_title == cmd.old_title.trim() {
+            return Err(OrganizationError::InvalidStructure(
+                format!("Role title is already '{}'", new_title)
+            ));
+        }
+        if self.find_roles_by_title(&cmd.old_title).is_empty() {
+            return Err(OrganizationError::EntityNotFound(
+                format!("No role titled '{}'", cmd.old_title.trim())
+            ));
+        }
+        Ok(())
     }
 
-    /// Apply an event to update aggregate state (mutable wrapper for compatibility)
-    /// This is a compatibility wrapper - prefer `apply_event_pure` for pure functional approach
-    pub fn apply_event(&mut self, event: &OrganizationEvent) -> OrganizationResult<()> {
-        let new_aggregate = self.apply_event_pure(event)?;
-        *self = new_aggregate;
+    /// Check the depth cap for placing a role, and everything below it, under `manager_id`
+    ///
+    /// `role_id` is `None` for a role that does not exist yet.
+    fn validate_reporting_depth(
+        &self,
+        role_id: Option<&EntityId<Role>>,
+        manager_id: &EntityId<Role>,
+    ) -> OrganizationResult<()> {
+        let Some(max_depth) = self.max_reporting_depth else {
+            return Ok(());
+        };
+
+        // Layers below the moved role: the furthest it appears up any chain
+        let layers_below = role_id.map_or(0, |role_id| {
+            self.roles
+                .keys()
+                .filter_map(|other| {
+                    self.reporting_chain(other).iter().position(|role| &role.id == role_id)
+                })
+                .max()
+                .unwrap_or(0)
+        });
+        let depth = self.reporting_chain(manager_id).len() + 1 + layers_below;
+
+        if depth > max_depth {
+            return Err(OrganizationError::InvalidStructure(format!(
+                "Reporting depth of {} exceeds the cap of {}",
+                depth, max_depth
+            )));
+        }
+        Ok(())
+    }
+
+    fn validate_executive_capacity(&self) -> OrganizationResult<()> {
+        if let Some(max_executives) = self.max_executives {
+            let executives = self.role_count_by_type().get(&RoleType::Executive).copied().unwrap_or(0);
+            if executives >= max_executives {
+                return Err(OrganizationError::InvalidStructure(
+                    format!("Executive cap of {} reached", max_executives)
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_add_child_organization(&self, cmd: &AddChildOrganization) -> OrganizationResult<()> {
+        // Check for self-reference (circular reference)
+        if cmd.child_organization_id == self.id {
+            return Err(OrganizationError::CircularReference("Organization cannot be its own child".to_string()));
+        }
+
+        // Check if child organization already exists
+        if self.child_organizations.contains_key(&cmd.child_organization_id) {
+            return Err(OrganizationError::DuplicateEntity(cmd.child_organization_id.to_string()));
+        }
+        Ok(())
+    }
+
+    fn validate_remove_child_organization(&self, cmd: &RemoveChildOrganization) -> OrganizationResult<()> {
+        if !self.child_organizations.contains_key(&cmd.child_organization_id) {
+            return Err(OrganizationError::OrganizationNotFound(cmd.child_organization_id));
+        }
+        Ok(())
+    }
+
+    fn validate_add_organization_tag(&self, cmd: &AddOrganizationTag) -> OrganizationResult<()> {
+        self.validate_organization_exists(&cmd.organization_id)?;
+
+        let tag = normalize_tag(&cmd.tag);
+        if tag.is_empty() {
+            return Err(OrganizationError::InvalidStructure("Tag cannot be empty".to_string()));
+        }
+        if self.tags.contains(&tag) {
+            return Err(OrganizationError::DuplicateEntity(format!("Tag '{}'", tag)));
+        }
+        Ok(())
+    }
+
+    fn validate_remove_organization_tag(&self, cmd: &RemoveOrganizationTag) -> OrganizationResult<()> {
+        self.validate_organization_exists(&cmd.organization_id)?;
+
+        let tag = normalize_tag(&cmd.tag);
+        if !self.tags.contains(&tag) {
+            return Err(OrganizationError::EntityNotFound(format!("Tag '{}'", tag)));
+        }
+        Ok(())
+    }
+
+    fn validate_organization_exists(&self, organization_id: &EntityId<Organization>) -> OrganizationResult<()> {
+        if self.organization.is_none() {
+            return Err(OrganizationError::OrganizationNotFound(organization_id.clone().into()));
+        }
+        Ok(())
+    }
+
+    fn validate_department_exists(&self, department_id: &EntityId<Department>) -> OrganizationResult<()> {
+        if !self.departments.contains_key(department_id) {
+            return Err(OrganizationError::DepartmentNotFound(department_id.clone().into()));
+        }
+        Ok(())
+    }
+
+    fn validate_team_exists(&self, team_id: &EntityId<Team>) -> OrganizationResult<()> {
+        if !self.teams.contains_key(team_id) {
+            return Err(OrganizationError::TeamNotFound(team_id.clone().into()));
+        }
+        Ok(())
+    }
+
+    fn validate_role_exists(&self, role_id: &EntityId<Role>) -> OrganizationResult<&Role> {
+        self.roles
+            .get(role_id)
+            .ok_or_else(|| OrganizationError::EntityNotFound(format!("Role {} not found", role_id)))
+    }
+
+    /// Solid-line reporting stays inside one organization
+    ///
+    /// A manager id that is not a role of this aggregate most likely belongs
+    /// to another organization. Cross-organization reporting is a dotted-line
+    /// relationship and is modelled in the Association domain.
+    fn validate_manager_exists(&self, manager_id: &EntityId<Role>) -> OrganizationResult<()> {
+        if !self.roles.contains_key(manager_id) {
+            return Err(OrganizationError::EntityNotFound(format!(
+                "Manager role {} is not part of organization {}",
+                manager_id, self.id
+            )));
+        }
+        Ok(())
+    }
+
+    fn validate_facility_exists(&self, facility_id: &EntityId<Facility>) -> OrganizationResult<()> {
+        if !self.facilities.contains_key(facility_id) {
+            return Err(OrganizationError::EntityNotFound(format!("Facility {} not found", facility_id)));
+        }
         Ok(())
     }
 
     // Command handlers
 
     fn handle_create_organization(&mut self, cmd: CreateOrganization) -> OrganizationResult<Vec<OrganizationEvent>> {
-        if self.organization.is_some() {
-            return Err(OrganizationError::DuplicateEntity("Organization already exists".to_string()));
-        }
+        self.validate_create_organization(&cmd)?;
 
         let org_id = EntityId::new();
         let event = OrganizationCreated {
@@ -401,6 +2178,7 @@ impl OrganizationAggregate {
             display_name: cmd.display_name,
             organization_type: cmd.organization_type,
             parent_id: cmd.parent_id,
+            founded_date: cmd.founded_date,
             metadata: cmd.metadata,
             occurred_at: Utc::now(),
         };
@@ -409,9 +2187,7 @@ impl OrganizationAggregate {
     }
 
     fn handle_update_organization(&mut self, cmd: UpdateOrganization) -> OrganizationResult<Vec<OrganizationEvent>> {
-        if self.organization.is_none() {
-            return Err(OrganizationError::OrganizationNotFound(cmd.organization_id.into()));
-        }
+        self.validate_update_organization(&cmd)?;
 
         let event = OrganizationUpdated {
             event_id: Uuid::now_v7(),
@@ -430,10 +2206,22 @@ impl OrganizationAggregate {
         Ok(vec![OrganizationEvent::OrganizationUpdated(event)])
     }
 
+    fn handle_update_organization_metadata(&mut self, cmd: UpdateOrganizationMetadata) -> OrganizationResult<Vec<OrganizationEvent>> {
+        self.validate_update_organization_metadata(&cmd)?;
+
+        let event = OrganizationMetadataUpdated {
+            event_id: Uuid::now_v7(),
+            identity: cmd.identity,
+            organization_id: cmd.organization_id,
+            patch: cmd.patch,
+            occurred_at: Utc::now(),
+        };
+
+        Ok(vec![OrganizationEvent::OrganizationMetadataUpdated(event)])
+    }
+
     fn handle_dissolve_organization(&mut self, cmd: DissolveOrganization) -> OrganizationResult<Vec<OrganizationEvent>> {
-        if self.organization.is_none() {
-            return Err(OrganizationError::OrganizationNotFound(cmd.organization_id.into()));
-        }
+        self.validate_dissolve_organization(&cmd)?;
 
         let event = OrganizationDissolved {
             event_id: Uuid::now_v7(),
@@ -448,15 +2236,11 @@ impl OrganizationAggregate {
     }
 
     fn handle_merge_organizations(&mut self, cmd: MergeOrganizations) -> OrganizationResult<Vec<OrganizationEvent>> {
-        if self.organization.is_none() {
-            return Err(OrganizationError::OrganizationNotFound(cmd.surviving_organization_id.into()));
-        }
-
-        // Check for self-merge
-        if cmd.surviving_organization_id == cmd.merged_organization_id {
-            return Err(OrganizationError::CircularReference("Organization cannot merge with itself".to_string()));
-        }
+        self.validate_merge_organizations(&cmd)?;
 
+        // The survivor only knows the merged organization through the counterpart
+        let merged_organization_name = cmd.counterpart.as_ref().and_then(|counterpart| counterpart.name.clone());
+        let merged_organization_type = cmd.counterpart.as_ref().map(|counterpart| counterpart.org_type.clone());
         let event = OrganizationMerged {
             event_id: Uuid::now_v7(),
             identity: cmd.identity,
@@ -464,18 +2248,65 @@ impl OrganizationAggregate {
             merged_organization_id: cmd.merged_organization_id,
             merger_type: cmd.merger_type,
             effective_date: cmd.effective_date,
+            merged_organization_name,
+            merged_organization_type,
             occurred_at: Utc::now(),
         };
 
         Ok(vec![OrganizationEvent::OrganizationMerged(event)])
     }
 
+    fn handle_complete_merge(&mut self, cmd: CompleteMerge) -> OrganizationResult<Vec<OrganizationEvent>> {
+        self.validate_complete_merge(&cmd)?;
+
+        // An acquired organization lives on as a subsidiary of the acquirer,
+        // every other merger type folds it into the survivor
+        let acquisition = matches!(cmd.merger_type, MergerType::Acquisition);
+        let new_status = if acquisition {
+            OrganizationStatus::Acquired
+        } else {
+            OrganizationStatus::Merged
+        };
+        let event = crate::events::OrganizationStatusChanged {
+            event_id: Uuid::now_v7(),
+            identity: cmd.identity,
+            organization_id: cmd.merged_organization_id,
+            new_status,
+            previous_status: self.status.clone(),
+            reason: Some(format!(
+                "{:?} by organization {}",
+                cmd.merger_type, cmd.surviving_organization_id
+            )),
+            parent_id: acquisition.then_some(cmd.surviving_organization_id),
+            occurred_at: Utc::now(),
+        };
+
+        Ok(vec![OrganizationEvent::OrganizationStatusChanged(event)])
+    }
+
     fn handle_create_department(&mut self, cmd: CreateDepartment) -> OrganizationResult<Vec<OrganizationEvent>> {
-        if self.organization.is_none() {
-            return Err(OrganizationError::OrganizationNotFound(cmd.organization_id.into()));
-        }
+        self.validate_create_department(&cmd)?;
 
         let dept_id = EntityId::new();
+        let now = Utc::now();
+        let head_role = cmd.seed_head_role.then(|| RoleCreated {
+            event_id: Uuid::now_v7(),
+            identity: cmd.identity.clone(),
+            role_id: EntityId::new(),
+            organization_id: cmd.organization_id.clone(),
+            department_id: Some(dept_id.clone()),
+            team_id: None,
+            title: format!("Head of {}", cmd.name),
+            code: format!("{}-HEAD", cmd.code),
+            description: None,
+            role_type: RoleType::Management,
+            level: None,
+            reports_to: None,
+            permissions: vec![Permission::ManageDepartment.as_str().to_string()],
+            responsibilities: Vec::new(),
+            occurred_at: now,
+        });
+
         let event = DepartmentCreated {
             event_id: Uuid::now_v7(),
             identity: cmd.identity,
@@ -484,16 +2315,18 @@ impl OrganizationAggregate {
             parent_department_id: cmd.parent_department_id,
             name: cmd.name,
             code: cmd.code,
-            occurred_at: Utc::now(),
+            default_permissions: cmd.default_permissions,
+            head_role_id: head_role.as_ref().map(|role| role.role_id.clone()),
+            occurred_at: now,
         };
 
-        Ok(vec![OrganizationEvent::DepartmentCreated(event)])
+        let mut events = vec![OrganizationEvent::DepartmentCreated(event)];
+        events.extend(head_role.map(OrganizationEvent::RoleCreated));
+        Ok(events)
     }
 
     fn handle_update_department(&mut self, cmd: UpdateDepartment) -> OrganizationResult<Vec<OrganizationEvent>> {
-        if !self.departments.contains_key(&cmd.department_id) {
-            return Err(OrganizationError::DepartmentNotFound(cmd.department_id.into()));
-        }
+        self.validate_department_exists(&cmd.department_id)?;
 
         let event = DepartmentUpdated {
             event_id: Uuid::now_v7(),
@@ -514,9 +2347,7 @@ impl OrganizationAggregate {
     }
 
     fn handle_restructure_department(&mut self, cmd: RestructureDepartment) -> OrganizationResult<Vec<OrganizationEvent>> {
-        if !self.departments.contains_key(&cmd.department_id) {
-            return Err(OrganizationError::DepartmentNotFound(cmd.department_id.into()));
-        }
+        self.validate_department_exists(&cmd.department_id)?;
 
         let event = DepartmentRestructured {
             event_id: Uuid::now_v7(),
@@ -532,9 +2363,7 @@ impl OrganizationAggregate {
     }
 
     fn handle_dissolve_department(&mut self, cmd: DissolveDepartment) -> OrganizationResult<Vec<OrganizationEvent>> {
-        if !self.departments.contains_key(&cmd.department_id) {
-            return Err(OrganizationError::DepartmentNotFound(cmd.department_id.into()));
-        }
+        self.validate_dissolve_department(&cmd)?;
 
         let event = DepartmentDissolved {
             event_id: Uuid::now_v7(),
@@ -550,9 +2379,7 @@ impl OrganizationAggregate {
     }
 
     fn handle_create_team(&mut self, cmd: CreateTeam) -> OrganizationResult<Vec<OrganizationEvent>> {
-        if self.organization.is_none() {
-            return Err(OrganizationError::OrganizationNotFound(cmd.organization_id.into()));
-        }
+        self.validate_create_team(&cmd)?;
 
         let team_id = EntityId::new();
         let event = TeamFormed {
@@ -570,9 +2397,7 @@ impl OrganizationAggregate {
     }
 
     fn handle_update_team(&mut self, cmd: UpdateTeam) -> OrganizationResult<Vec<OrganizationEvent>> {
-        if !self.teams.contains_key(&cmd.team_id) {
-            return Err(OrganizationError::TeamNotFound(cmd.team_id.into()));
-        }
+        self.validate_team_exists(&cmd.team_id)?;
 
         let event = TeamUpdated {
             event_id: Uuid::now_v7(),
@@ -593,9 +2418,7 @@ impl OrganizationAggregate {
     }
 
     fn handle_disband_team(&mut self, cmd: DisbandTeam) -> OrganizationResult<Vec<OrganizationEvent>> {
-        if !self.teams.contains_key(&cmd.team_id) {
-            return Err(OrganizationError::TeamNotFound(cmd.team_id.into()));
-        }
+        self.validate_disband_team(&cmd)?;
 
         let event = TeamDisbanded {
             event_id: Uuid::now_v7(),
@@ -611,9 +2434,7 @@ impl OrganizationAggregate {
     }
 
     fn handle_create_role(&mut self, cmd: CreateRole) -> OrganizationResult<Vec<OrganizationEvent>> {
-        if self.organization.is_none() {
-            return Err(OrganizationError::OrganizationNotFound(cmd.organization_id.into()));
-        }
+        self.validate_create_role(&cmd)?;
 
         let role_id = EntityId::new();
         let event = RoleCreated {
@@ -638,7 +2459,9 @@ impl OrganizationAggregate {
     }
 
     fn handle_update_role(&mut self, cmd: UpdateRole) -> OrganizationResult<Vec<OrganizationEvent>> {
-        // Validation would go here
+        self.validate_update_role(&cmd)?;
+
+        let (gained_permissions, lost_permissions) = self.permission_diff(&cmd.role_id, cmd.permissions.as_ref());
         let event = RoleUpdated {
             event_id: Uuid::now_v7(),
             identity: cmd.identity,
@@ -647,18 +2470,103 @@ impl OrganizationAggregate {
             changes: RoleChanges {
                 title: cmd.title,
                 description: cmd.description,
+                role_type: cmd.role_type,
                 level: cmd.level,
                 reports_to: cmd.reports_to,
                 permissions: cmd.permissions,
                 responsibilities: cmd.responsibilities,
                 status: cmd.status,
             },
+            gained_permissions,
+            lost_permissions,
             occurred_at: Utc::now(),
         };
 
         Ok(vec![OrganizationEvent::RoleUpdated(event)])
     }
 
+    fn handle_swap_reporting(&mut self, cmd: SwapReporting) -> OrganizationResult<Vec<OrganizationEvent>> {
+        self.validate_swap_reporting(&cmd)?;
+        Ok(self.swap_reporting_events(&cmd))
+    }
+
+    fn handle_rename_role_title(&mut self, cmd: RenameRoleTitle) -> OrganizationResult<Vec<OrganizationEvent>> {
+        self.validate_rename_role_title(&cmd)?;
+
+        let new_title = cmd.new_title.trim().to_string();
+        let events = self
+            .find_roles_by_title(&cmd.old_title)
+            .into_iter()
+            .map(|role| {
+                OrganizationEvent::RoleUpdated(RoleUpdated {
+                    event_id: Uuid::now_v7(),
+                    identity: cmd.identity.clone(),
+                    role_id: role.id.clone(),
+                    organization_id: cmd.organization_id.clone(),
+                    changes: RoleChanges {
+                        title: Some(new_title.clone()),
+                        description: None,
+                        role_type: None,
+                        level: None,
+                        reports_to: None,
+                        permissions: None,
+                        responsibilities: None,
+                        status: None,
+                    },
+                    gained_permissions: Vec::new(),
+                    lost_permissions: Vec::new(),
+                    occurred_at: Utc::now(),
+                })
+            })
+            .collect();
+
+        Ok(events)
+    }
+
+    /// One `RoleUpdated` per non-deprecated direct report of either manager
+    fn swap_reporting_events(&self, cmd: &SwapReporting) -> Vec<OrganizationEvent> {
+        let mut reports: Vec<&Role> = self
+            .roles
+            .values()
+            .filter(|role| role.status != RoleStatus::Deprecated)
+            .filter(|role| {
+                role.reports_to.as_ref() == Some(&cmd.manager_a)
+                    || role.reports_to.as_ref() == Some(&cmd.manager_b)
+            })
+            .collect();
+        reports.sort_by_key(|role| (role.created_at, role.id.to_string()));
+
+        reports
+            .into_iter()
+            .map(|role| {
+                let new_manager = if role.reports_to.as_ref() == Some(&cmd.manager_a) {
+                    cmd.manager_b.clone()
+                } else {
+                    cmd.manager_a.clone()
+                };
+                OrganizationEvent::RoleUpdated(RoleUpdated {
+                    event_id: Uuid::now_v7(),
+                    identity: cmd.identity.clone(),
+                    role_id: role.id.clone(),
+                    organization_id: cmd.organization_id.clone(),
+                    changes: RoleChanges {
+                        title: None,
+                        description: None,
+                        role_type: None,
+                        level: None,
+                        reports_to: Some(new_manager),
+                        permissions: None,
+                        responsibilities: None,
+                        status: None,
+                    },
+                    gained_permissions: Vec::new(),
+                    lost_permissions: Vec::new(),
+                    occurred_at: Utc::now(),
+                })
+            })
+            .collect()
+    }
+
     fn handle_deprecate_role(&mut self, cmd: DeprecateRole) -> OrganizationResult<Vec<OrganizationEvent>> {
         let event = RoleDeprecated {
             event_id: Uuid::now_v7(),
@@ -695,10 +2603,7 @@ impl OrganizationAggregate {
     }
 
     fn handle_update_facility(&mut self, cmd: UpdateFacility) -> OrganizationResult<Vec<OrganizationEvent>> {
-        // Check if facility exists
-        if !self.facilities.contains_key(&cmd.facility_id) {
-            return Err(OrganizationError::EntityNotFound(format!("Facility {} not found", cmd.facility_id)));
-        }
+        self.validate_facility_exists(&cmd.facility_id)?;
 
         let event = FacilityUpdated {
             event_id: Uuid::now_v7(),
@@ -720,10 +2625,7 @@ impl OrganizationAggregate {
     }
 
     fn handle_remove_facility(&mut self, cmd: RemoveFacility) -> OrganizationResult<Vec<OrganizationEvent>> {
-        // Check if facility exists
-        if !self.facilities.contains_key(&cmd.facility_id) {
-            return Err(OrganizationError::EntityNotFound(format!("Facility {} not found", cmd.facility_id)));
-        }
+        self.validate_facility_exists(&cmd.facility_id)?;
 
         let event = FacilityRemoved {
             event_id: Uuid::now_v7(),
@@ -740,15 +2642,7 @@ impl OrganizationAggregate {
     // Hierarchy handlers
 
     fn handle_add_child_organization(&mut self, cmd: AddChildOrganization) -> OrganizationResult<Vec<OrganizationEvent>> {
-        // Check for self-reference (circular reference)
-        if cmd.child_organization_id == self.id {
-            return Err(OrganizationError::CircularReference("Organization cannot be its own child".to_string()));
-        }
-
-        // Check if child organization already exists
-        if self.child_organizations.contains_key(&cmd.child_organization_id) {
-            return Err(OrganizationError::DuplicateEntity(cmd.child_organization_id.to_string()));
-        }
+        self.validate_add_child_organization(&cmd)?;
 
         let event = crate::events::ChildOrganizationAdded {
             event_id: Uuid::now_v7(),
@@ -764,10 +2658,7 @@ impl OrganizationAggregate {
     }
 
     fn handle_remove_child_organization(&mut self, cmd: RemoveChildOrganization) -> OrganizationResult<Vec<OrganizationEvent>> {
-        // Check if child organization exists
-        if !self.child_organizations.contains_key(&cmd.child_organization_id) {
-            return Err(OrganizationError::OrganizationNotFound(cmd.child_organization_id));
-        }
+        self.validate_remove_child_organization(&cmd)?;
 
         let event = crate::events::ChildOrganizationRemoved {
             event_id: Uuid::now_v7(),
@@ -780,15 +2671,41 @@ impl OrganizationAggregate {
         Ok(vec![OrganizationEvent::ChildOrganizationRemoved(event)])
     }
 
+    // Tag handlers
+
+    fn handle_add_organization_tag(&mut self, cmd: AddOrganizationTag) -> OrganizationResult<Vec<OrganizationEvent>> {
+        self.validate_add_organization_tag(&cmd)?;
+
+        let event = crate::events::OrganizationTagAdded {
+            event_id: Uuid::now_v7(),
+            identity: cmd.identity,
+            organization_id: cmd.organization_id,
+            tag: normalize_tag(&cmd.tag),
+            occurred_at: Utc::now(),
+        };
+
+        Ok(vec![OrganizationEvent::OrganizationTagAdded(event)])
+    }
+
+    fn handle_remove_organization_tag(&mut self, cmd: RemoveOrganizationTag) -> OrganizationResult<Vec<OrganizationEvent>> {
+        self.validate_remove_organization_tag(&cmd)?;
+
+        let event = crate::events::OrganizationTagRemoved {
+            event_id: Uuid::now_v7(),
+            identity: cmd.identity,
+            organization_id: cmd.organization_id,
+            tag: normalize_tag(&cmd.tag),
+            occurred_at: Utc::now(),
+        };
+
+        Ok(vec![OrganizationEvent::OrganizationTagRemoved(event)])
+    }
+
     // Status handlers
 
     fn handle_change_organization_status(&mut self, cmd: ChangeOrganizationStatus) -> OrganizationResult<Vec<OrganizationEvent>> {
         // Validate status transition
-        if !self.is_valid_status_transition(self.status.clone(), cmd.new_status.clone()) {
-            return Err(OrganizationError::InvalidStructure(
-                format!("Invalid status transition from {:?} to {:?}", self.status, cmd.new_status)
-            ));
-        }
+        self.validate_change_organization_status(&cmd)?;
 
         // Create event
         let event = crate::events::OrganizationStatusChanged {
@@ -798,6 +2715,7 @@ impl OrganizationAggregate {
             new_status: cmd.new_status.clone(),
             previous_status: self.status.clone(),
             reason: cmd.reason,
+            parent_id: None,
             occurred_at: Utc::now(),
         };
 
@@ -819,6 +2737,8 @@ impl OrganizationAggregate {
             (Inactive, Active) => true,
             // Suspended can transition to Active (unsuspend) or Dissolved
             (Suspended, Active) | (Suspended, Dissolved) => true,
+            // Acquired subsidiaries can resume operating or be wound down
+            (Acquired, Active) | (Acquired, Dissolved) => true,
             // Dissolved and Merged are terminal states - no transitions allowed
             (Dissolved, _) | (Merged, _) => false,
             // All other transitions are invalid
@@ -827,6 +2747,97 @@ impl OrganizationAggregate {
     }
 }
 
+/// The wanted value when it differs from the current one
+fn changed<T: PartialEq + Clone>(current: &T, wanted: &T) -> Option<T> {
+    if current != wanted {
+        Some(wanted.clone())
+    } else {
+        None
+    }
+}
+
+/// Where an event sits in the lifecycle of a removable part of the aggregate
+enum LifecycleStep {
+    Open,
+    Touch,
+    Close,
+}
+
+/// Key and lifecycle step for events that `compact_events` can pair up
+fn lifecycle_step(event: &OrganizationEvent) -> Option<(String, LifecycleStep)> {
+    match event {
+        OrganizationEvent::ChildOrganizationAdded(e) => {
+            Some((format!("child:{}", e.child_organization_id), LifecycleStep::Open))
+        }
+        // An acquisition re-adds the acquired organization as a child
+        OrganizationEvent::OrganizationMerged(e) if matches!(e.merger_type, MergerType::Acquisition) => {
+            Some((format!("child:{}", e.merged_organization_id), LifecycleStep::Touch))
+        }
+        OrganizationEvent::ChildOrganizationRemoved(e) => {
+            Some((format!("child:{}", e.child_organization_id), LifecycleStep::Close))
+        }
+        OrganizationEvent::OrganizationTagAdded(e) => Some((format!("tag:{}", e.tag), LifecycleStep::Open)),
+        OrganizationEvent::OrganizationTagRemoved(e) => Some((format!("tag:{}", e.tag), LifecycleStep::Close)),
+        OrganizationEvent::FacilityCreated(e) => Some((format!("facility:{}", e.facility_id), LifecycleStep::Open)),
+        OrganizationEvent::FacilityUpdated(e) => Some((format!("facility:{}", e.facility_id), LifecycleStep::Touch)),
+        OrganizationEvent::FacilityRemoved(e) => Some((format!("facility:{}", e.facility_id), LifecycleStep::Close)),
+        _ => None,
+    }
+}
+
+/// Comma-separated role ids for error messages
+fn format_role_ids<'a>(role_ids: impl IntoIterator<Item = &'a EntityId<Role>>) -> String {
+    role_ids.into_iter().map(|id| id.to_string()).collect::<Vec<_>>().join(", ")
+}
+
+/// Quote a CSV field when it contains a separator, quote or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Canonical form of a tag: trimmed and lowercased
+fn normalize_tag(tag: &str) -> String {
+    tag.trim().to_lowercase()
+}
+
+/// Apply a JSON merge patch (RFC 7386) to `target` in place
+fn merge_patch(target: &mut serde_json::Value, patch: &serde_json::Value) {
+    let serde_json::Value::Object(patch) = patch else {
+        *target = patch.clone();
+        return;
+    };
+    if !target.is_object() {
+        *target = serde_json::Value::Object(serde_json::Map::new());
+    }
+    if let serde_json::Value::Object(target) = target {
+        for (key, value) in patch {
+            if value.is_null() {
+                target.remove(key);
+            } else {
+                merge_patch(target.entry(key.clone()).or_insert(serde_json::Value::Null), value);
+            }
+        }
+    }
+}
+
+/// A `tax_id` in organization metadata must suit the organization type;
+/// null or absent passes
+fn validate_metadata_tax_id(org_type: &OrganizationType, metadata: &serde_json::Value) -> OrganizationResult<()> {
+    match metadata.get("tax_id") {
+        Some(serde_json::Value::String(tax_id)) => {
+            org_type.validate_tax_id(tax_id).map_err(OrganizationError::InvalidStructure)
+        }
+        Some(serde_json::Value::Null) | None => Ok(()),
+        Some(_) => Err(OrganizationError::InvalidStructure(
+            "Metadata tax_id must be a string".to_string()
+        )),
+    }
+}
+
 /// MealyStateMachine implementation for OrganizationAggregate
 ///
 /// This implements the pure functional state machine pattern from Category Theory
@@ -872,8 +2883,13 @@ impl MealyStateMachine for OrganizationAggregate {
             (Active, DissolveOrganization(_)) => Dissolved,
             (Active, ChangeOrganizationStatus(cmd)) if matches!(cmd.new_status, OrganizationStatus::Dissolved) => Dissolved,
 
+            // Active → Acquired (acquisition keeps the organization as a subsidiary);
+            // the merged party moves on CompleteMerge, the survivor stays as it is
+            (Active | Inactive, CompleteMerge(cmd))
+                if self.is_merged_party(&cmd) && matches!(cmd.merger_type, MergerType::Acquisition) => Acquired,
+
             // Active → Merged (merger)
-            (Active, MergeOrganizations(_)) => Merged,
+            (Active | Inactive, CompleteMerge(cmd)) if self.is_merged_party(&cmd) => Merged,
             (Active, ChangeOrganizationStatus(cmd)) if matches!(cmd.new_status, OrganizationStatus::Merged) => Merged,
 
             // Inactive → Active (reactivation)
@@ -886,6 +2902,11 @@ impl MealyStateMachine for OrganizationAggregate {
             (Suspended, DissolveOrganization(_)) => Dissolved,
             (Suspended, ChangeOrganizationStatus(cmd)) if matches!(cmd.new_status, OrganizationStatus::Dissolved) => Dissolved,
 
+            // Acquired → Active / Dissolved
+            (Acquired, ChangeOrganizationStatus(cmd)) if matches!(cmd.new_status, OrganizationStatus::Active) => Active,
+            (Acquired, DissolveOrganization(_)) => Dissolved,
+            (Acquired, ChangeOrganizationStatus(cmd)) if matches!(cmd.new_status, OrganizationStatus::Dissolved) => Dissolved,
+
             // Terminal states - no transitions
             (Dissolved, _) => Dissolved,
             (Merged, _) => Merged,
@@ -25,8 +25,10 @@ use crate::aggregate::OrganizationAggregate;
 pub enum OrganizationCommand {
     CreateOrganization(CreateOrganization),
     UpdateOrganization(UpdateOrganization),
+    UpdateOrganizationMetadata(UpdateOrganizationMetadata),
     DissolveOrganization(DissolveOrganization),
     MergeOrganizations(MergeOrganizations),
+    CompleteMerge(CompleteMerge),
     ChangeOrganizationStatus(ChangeOrganizationStatus),
     CreateDepartment(CreateDepartment),
     UpdateDepartment(UpdateDepartment),
@@ -38,11 +40,15 @@ pub enum OrganizationCommand {
     CreateRole(CreateRole),
     UpdateRole(UpdateRole),
     DeprecateRole(DeprecateRole),
+    SwapReporting(SwapReporting),
+    RenameRoleTitle(RenameRoleTitle),
     CreateFacility(CreateFacility),
     UpdateFacility(UpdateFacility),
     RemoveFacility(RemoveFacility),
     AddChildOrganization(AddChildOrganization),
     RemoveChildOrganization(RemoveChildOrganization),
+    AddOrganizationTag(AddOrganizationTag),
+    RemoveOrganizationTag(RemoveOrganizationTag),
 }
 
 impl Command for OrganizationCommand {
@@ -52,8 +58,10 @@ impl Command for OrganizationCommand {
         match self {
             OrganizationCommand::CreateOrganization(_) => None, // New aggregate
             OrganizationCommand::UpdateOrganization(cmd) => Some(EntityId::from_uuid(cmd.organization_id.clone().into())),
+            OrganizationCommand::UpdateOrganizationMetadata(cmd) => Some(EntityId::from_uuid(cmd.organization_id.clone().into())),
             OrganizationCommand::DissolveOrganization(cmd) => Some(EntityId::from_uuid(cmd.organization_id.clone().into())),
             OrganizationCommand::MergeOrganizations(cmd) => Some(EntityId::from_uuid(cmd.surviving_organization_id.clone().into())),
+            OrganizationCommand::CompleteMerge(cmd) => Some(EntityId::from_uuid(cmd.merged_organization_id.clone().into())),
             OrganizationCommand::ChangeOrganizationStatus(cmd) => Some(EntityId::from_uuid(cmd.organization_id)),
             OrganizationCommand::CreateDepartment(cmd) => Some(EntityId::from_uuid(cmd.organization_id.clone().into())),
             OrganizationCommand::UpdateDepartment(cmd) => Some(EntityId::from_uuid(cmd.organization_id.clone().into())),
@@ -65,11 +73,49 @@ impl Command for OrganizationCommand {
             OrganizationCommand::CreateRole(cmd) => Some(EntityId::from_uuid(cmd.organization_id.clone().into())),
             OrganizationCommand::UpdateRole(cmd) => Some(EntityId::from_uuid(cmd.organization_id.clone().into())),
             OrganizationCommand::DeprecateRole(cmd) => Some(EntityId::from_uuid(cmd.organization_id.clone().into())),
+            OrganizationCommand::SwapReporting(cmd) => Some(EntityId::from_uuid(cmd.organization_id.clone().into())),
+            OrganizationCommand::RenameRoleTitle(cmd) => Some(EntityId::from_uuid(cmd.organization_id.clone().into())),
             OrganizationCommand::CreateFacility(cmd) => Some(EntityId::from_uuid(cmd.organization_id.clone().into())),
             OrganizationCommand::UpdateFacility(cmd) => Some(EntityId::from_uuid(cmd.organization_id.clone().into())),
             OrganizationCommand::RemoveFacility(cmd) => Some(EntityId::from_uuid(cmd.organization_id.clone().into())),
             OrganizationCommand::AddChildOrganization(cmd) => Some(EntityId::from_uuid(cmd.parent_organization_id)),
             OrganizationCommand::RemoveChildOrganization(cmd) => Some(EntityId::from_uuid(cmd.parent_organization_id)),
+            OrganizationCommand::AddOrganizationTag(cmd) => Some(EntityId::from_uuid(cmd.organization_id.clone().into())),
+            OrganizationCommand::RemoveOrganizationTag(cmd) => Some(EntityId::from_uuid(cmd.organization_id.clone().into())),
+        }
+    }
+}
+
+impl OrganizationCommand {
+    /// Name of the command variant, matching its serialized `command_type`
+    pub fn command_type(&self) -> &'static str {
+        match self {
+            OrganizationCommand::CreateOrganization(_) => "CreateOrganization",
+            OrganizationCommand::UpdateOrganization(_) => "UpdateOrganization",
+            OrganizationCommand::UpdateOrganizationMetadata(_) => "UpdateOrganizationMetadata",
+            OrganizationCommand::DissolveOrganization(_) => "DissolveOrganization",
+            OrganizationCommand::MergeOrganizations(_) => "MergeOrganizations",
+            OrganizationCommand::CompleteMerge(_) => "CompleteMerge",
+            OrganizationCommand::ChangeOrganizationStatus(_) => "ChangeOrganizationStatus",
+            OrganizationCommand::CreateDepartment(_) => "CreateDepartment",
+            OrganizationCommand::UpdateDepartment(_) => "UpdateDepartment",
+            OrganizationCommand::RestructureDepartment(_) => "RestructureDepartment",
+            OrganizationCommand::DissolveDepartment(_) => "DissolveDepartment",
+            OrganizationCommand::CreateTeam(_) => "CreateTeam",
+            OrganizationCommand::UpdateTeam(_) => "UpdateTeam",
+            OrganizationCommand::DisbandTeam(_) => "DisbandTeam",
+            OrganizationCommand::CreateRole(_) => "CreateRole",
+            OrganizationCommand::UpdateRole(_) => "UpdateRole",
+            OrganizationCommand::DeprecateRole(_) => "DeprecateRole",
+            OrganizationCommand::SwapReporting(_) => "SwapReporting",
+            OrganizationCommand::RenameRoleTitle(_) => "RenameRoleTitle",
+            OrganizationCommand::CreateFacility(_) => "CreateFacility",
+            OrganizationCommand::UpdateFacility(_) => "UpdateFacility",
+            OrganizationCommand::RemoveFacility(_) => "RemoveFacility",
+            OrganizationCommand::AddChildOrganization(_) => "AddChildOrganization",
+            OrganizationCommand::RemoveChildOrganization(_) => "RemoveChildOrganization",
+            OrganizationCommand::AddOrganizationTag(_) => "AddOrganizationTag",
+            OrganizationCommand::RemoveOrganizationTag(_) => "RemoveOrganizationTag",
         }
     }
 }
@@ -107,6 +153,10 @@ pub struct UpdateOrganization {
     pub description: Option<String>,
     pub status: Option<OrganizationStatus>,
     pub metadata: Option<serde_json::Value>,
+    /// Names of sibling organizations under the same parent, used to reject
+    /// a rename that would collide with one of them
+    #[serde(default)]
+    pub sibling_names: Vec<String>,
 }
 
 impl Command for UpdateOrganization {
@@ -117,6 +167,26 @@ impl Command for UpdateOrganization {
     }
 }
 
+/// Command: Merge a partial update into organization metadata
+///
+/// `patch` is a JSON merge patch (RFC 7386): keys it sets replace existing
+/// ones, `null` deletes a key, and keys it omits are left untouched.
+/// A `tax_id` key must be a string valid for the organization's type.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateOrganizationMetadata {
+    pub identity: MessageIdentity,
+    pub organization_id: EntityId<Organization>,
+    pub patch: serde_json::Value,
+}
+
+impl Command for UpdateOrganizationMetadata {
+    type Aggregate = OrganizationAggregate;
+
+    fn aggregate_id(&self) -> Option<EntityId<Self::Aggregate>> {
+        Some(EntityId::from_uuid(self.organization_id.clone().into()))
+    }
+}
+
 /// Command: Dissolve organization
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DissolveOrganization {
@@ -124,6 +194,10 @@ pub struct DissolveOrganization {
     pub organization_id: EntityId<Organization>,
     pub reason: String,
     pub effective_date: DateTime<Utc>,
+    /// Also dissolve every child organization, see
+    /// `OrganizationAggregate::cascade_to_children`
+    #[serde(default)]
+    pub cascade_to_children: bool,
 }
 
 impl Command for DissolveOrganization {
@@ -142,6 +216,20 @@ pub struct MergeOrganizations {
     pub merged_organization_id: EntityId<Organization>,
     pub merger_type: crate::events::MergerType,
     pub effective_date: DateTime<Utc>,
+    /// State of the other organization, which this aggregate cannot see.
+    /// Checked for merge compatibility when provided.
+    #[serde(default)]
+    pub counterpart: Option<MergeCounterpart>,
+}
+
+/// Status and type of the other party in a merge, as known to the caller
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MergeCounterpart {
+    pub status: OrganizationStatus,
+    pub org_type: OrganizationType,
+    /// Name to record when the survivor handles an acquisition
+    #[serde(default)]
+    pub name: Option<String>,
 }
 
 impl Command for MergeOrganizations {
@@ -152,6 +240,28 @@ impl Command for MergeOrganizations {
     }
 }
 
+/// Command: Record a merge on the organization it absorbed
+///
+/// `MergeOrganizations` is handled by the surviving organization. The command
+/// handler then sends this to the merged organization, see
+/// `OrganizationAggregate::merged_party_command`, so that its own stream
+/// records the move to `Acquired` or `Merged`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompleteMerge {
+    pub identity: MessageIdentity,
+    pub merged_organization_id: EntityId<Organization>,
+    pub surviving_organization_id: EntityId<Organization>,
+    pub merger_type: crate::events::MergerType,
+}
+
+impl Command for CompleteMerge {
+    type Aggregate = OrganizationAggregate;
+
+    fn aggregate_id(&self) -> Option<EntityId<Self::Aggregate>> {
+        Some(EntityId::from_uuid(self.merged_organization_id.clone().into()))
+    }
+}
+
 // Department commands
 
 /// Command: Create department
@@ -163,6 +273,11 @@ pub struct CreateDepartment {
     pub name: String,
     pub code: String,
     pub description: Option<String>,
+    #[serde(default)]
+    pub default_permissions: Vec<String>,
+    /// Also create a management role heading the new department
+    #[serde(default)]
+    pub seed_head_role: bool,
 }
 
 impl Command for CreateDepartment {
@@ -305,6 +420,8 @@ pub struct CreateRole {
     pub description: Option<String>,
     pub role_type: RoleType,
     pub level: Option<u8>,
+    /// Must be a role of the same organization; cross-organization
+    /// (dotted-line) reporting belongs in the Association domain
     pub reports_to: Option<EntityId<Role>>,
     pub permissions: Vec<String>,
     pub responsibilities: Vec<String>,
@@ -326,6 +443,8 @@ pub struct UpdateRole {
     pub organization_id: EntityId<Organization>,
     pub title: Option<String>,
     pub description: Option<String>,
+    #[serde(default)]
+    pub role_type: Option<RoleType>,
     pub level: Option<u8>,
     pub reports_to: Option<EntityId<Role>>,
     pub permissions: Option<Vec<String>>,
@@ -360,6 +479,48 @@ impl Command for DeprecateRole {
     }
 }
 
+/// Command: Swap the direct reports of two manager roles in one step
+///
+/// Reports of `manager_a` move to `manager_b` and vice versa. The end state
+/// is checked for cycles before any event is emitted, so no intermediate
+/// state with a cycle is ever recorded.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SwapReporting {
+    pub identity: MessageIdentity,
+    pub organization_id: EntityId<Organization>,
+    pub manager_a: EntityId<Role>,
+    pub manager_b: EntityId<Role>,
+}
+
+impl Command for SwapReporting {
+    type Aggregate = OrganizationAggregate;
+
+    fn aggregate_id(&self) -> Option<EntityId<Self::Aggregate>> {
+        Some(EntityId::from_uuid(self.organization_id.clone().into()))
+    }
+}
+
+/// Command: Retitle every non-deprecated role with a given title
+///
+/// `old_title` is matched ignoring case and surrounding whitespace, as in
+/// `OrganizationAggregate::find_roles_by_title`. Levels, permissions and
+/// reporting lines are left untouched.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RenameRoleTitle {
+    pub identity: MessageIdentity,
+    pub organization_id: EntityId<Organization>,
+    pub old_title: String,
+    pub new_title: String,
+}
+
+impl Command for RenameRoleTitle {
+    type Aggregate = OrganizationAggregate;
+
+    fn aggregate_id(&self) -> Option<EntityId<Self::Aggregate>> {
+        Some(EntityId::from_uuid(self.organization_id.clone().into()))
+    }
+}
+
 // Facility commands - pure organizational places (no location/address data)
 
 /// Command: Create facility
@@ -442,6 +603,24 @@ pub struct RemoveChildOrganization {
     pub child_organization_id: Uuid,
 }
 
+// Tag commands
+
+/// Command: Add a free-form tag to an organization
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddOrganizationTag {
+    pub identity: MessageIdentity,
+    pub organization_id: EntityId<Organization>,
+    pub tag: String,
+}
+
+/// Command: Remove a tag from an organization
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoveOrganizationTag {
+    pub identity: MessageIdentity,
+    pub organization_id: EntityId<Organization>,
+    pub tag: String,
+}
+
 // Status commands
 
 /// Command: Change organization status
@@ -451,4 +630,8 @@ pub struct ChangeOrganizationStatus {
     pub organization_id: Uuid,
     pub new_status: OrganizationStatus,
     pub reason: Option<String>,
+    /// Also move every child organization to the new status, see
+    /// `OrganizationAggregate::cascade_to_children`
+    #[serde(default)]
+    pub cascade_to_children: bool,
 }
\ No newline at end of file
@@ -2,9 +2,10 @@
 //!
 //! Core entities for organizational management following DDD principles
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
 use cim_domain::{DomainEntity, EntityId};
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 /// Organization entity - represents a company, business unit, or institution
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
@@ -43,6 +44,85 @@ pub enum OrganizationType {
     Other(String),
 }
 
+impl OrganizationType {
+    /// Check a tax identifier against the format expected for this type
+    ///
+    /// Incorporated, nonprofit and government bodies use a US EIN
+    /// (`NN-NNNNNNN`); sole proprietors may also use an SSN (`NNN-NN-NNNN`).
+    /// Other types only require a non-empty identifier.
+    pub fn validate_tax_id(&self, tax_id: &str) -> Result<(), String> {
+        let tax_id = tax_id.trim();
+        let valid = match self {
+            OrganizationType::Corporation
+            | OrganizationType::NonProfit
+            | OrganizationType::Government
+            | OrganizationType::Partnership
+            | OrganizationType::Cooperative
+            | OrganizationType::LLC => matches_digit_groups(tax_id, &[2, 7]),
+            OrganizationType::SoleProprietorship => {
+                matches_digit_groups(tax_id, &[2, 7]) || matches_digit_groups(tax_id, &[3, 2, 4])
+            }
+            OrganizationType::Other(_) => !tax_id.is_empty(),
+        };
+
+        if valid {
+            Ok(())
+        } else {
+            Err(format!("Invalid tax id '{}' for {:?}", tax_id, self))
+        }
+    }
+
+    /// Whether organizations of these two types may merge
+    ///
+    /// Mergers stay within a sector: government bodies merge with government
+    /// bodies and nonprofits with nonprofits, since their assets cannot pass
+    /// to a commercial entity. All other types are commercial.
+    pub fn can_merge_with(&self, other: &OrganizationType) -> bool {
+        let sector = |org_type: &OrganizationType| match org_type {
+            OrganizationType::Government => 0,
+            OrganizationType::NonProfit => 1,
+            _ => 2,
+        };
+        sector(self) == sector(other)
+    }
+
+    /// Icon and color to use when drawing this type in charts
+    ///
+    /// Data only; how the glyph is rendered is up to the consumer.
+    pub fn display_metadata(&self) -> DisplayMetadata {
+        let (icon, color) = match self {
+            OrganizationType::Corporation => ("building", "#1f6feb"),
+            OrganizationType::NonProfit => ("heart", "#2da44e"),
+            OrganizationType::Government => ("landmark", "#6e7781"),
+            OrganizationType::Partnership => ("handshake", "#bf8700"),
+            OrganizationType::SoleProprietorship => ("user", "#8250df"),
+            OrganizationType::Cooperative => ("users", "#1a7f37"),
+            OrganizationType::LLC => ("briefcase", "#0969da"),
+            OrganizationType::Other(_) => ("circle", "#57606a"),
+        };
+        DisplayMetadata { icon, color }
+    }
+}
+
+/// Chart glyph for an organization type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct DisplayMetadata {
+    /// Icon name, e.g. "building"
+    pub icon: &'static str,
+    /// Hex color, e.g. "#1f6feb"
+    pub color: &'static str,
+}
+
+/// Whether `value` is dash-separated groups of digits with the given lengths
+fn matches_digit_groups(value: &str, lengths: &[usize]) -> bool {
+    let groups: Vec<&str> = value.split('-').collect();
+    groups.len() == lengths.len()
+        && groups
+            .iter()
+            .zip(lengths)
+            .all(|(group, len)| group.len() == *len && group.chars().all(|c| c.is_ascii_digit()))
+}
+
 /// Organization status
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum OrganizationStatus {
@@ -52,6 +132,56 @@ pub enum OrganizationStatus {
     Suspended,
     Dissolved,
     Merged,
+    /// Acquired by another organization and kept as its subsidiary
+    Acquired,
+}
+
+impl OrganizationStatus {
+    /// Every status, in declaration order
+    pub const ALL: [OrganizationStatus; 7] = [
+        OrganizationStatus::Pending,
+        OrganizationStatus::Active,
+        OrganizationStatus::Inactive,
+        OrganizationStatus::Suspended,
+        OrganizationStatus::Dissolved,
+        OrganizationStatus::Merged,
+        OrganizationStatus::Acquired,
+    ];
+
+    /// How far this status restricts operation, for comparing statuses
+    ///
+    /// An acquired organization keeps operating as a subsidiary, so it ranks
+    /// with active ones.
+    pub(crate) fn restriction(&self) -> u8 {
+        match self {
+            OrganizationStatus::Active | OrganizationStatus::Acquired => 0,
+            OrganizationStatus::Pending => 1,
+            OrganizationStatus::Inactive => 2,
+            OrganizationStatus::Suspended => 3,
+            OrganizationStatus::Dissolved | OrganizationStatus::Merged => 4,
+        }
+    }
+}
+
+/// Parses the serialized variant name, ignoring case (`"active"`, `"Active"`)
+impl FromStr for OrganizationStatus {
+    type Err = crate::OrganizationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "pending" => Ok(OrganizationStatus::Pending),
+            "active" => Ok(OrganizationStatus::Active),
+            "inactive" => Ok(OrganizationStatus::Inactive),
+            "suspended" => Ok(OrganizationStatus::Suspended),
+            "dissolved" => Ok(OrganizationStatus::Dissolved),
+            "merged" => Ok(OrganizationStatus::Merged),
+            "acquired" => Ok(OrganizationStatus::Acquired),
+            _ => Err(crate::OrganizationError::InvalidStatus(format!(
+                "Unknown organization status '{}'",
+                s
+            ))),
+        }
+    }
 }
 
 /// Department entity - a division within an organization
@@ -64,6 +194,9 @@ pub struct Department {
     pub code: String,
     pub description: Option<String>,
     pub head_role_id: Option<EntityId<Role>>,
+    /// Permissions granted to every role in the department
+    #[serde(default)]
+    pub default_permissions: Vec<String>,
     pub status: DepartmentStatus,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -139,13 +272,18 @@ pub struct Role {
     pub department_id: Option<EntityId<Department>>,
     pub team_id: Option<EntityId<Team>>,
     pub title: String,
+    #[serde(default)]
     pub code: String,
     pub description: Option<String>,
+    #[serde(default)]
     pub role_type: RoleType,
     pub level: Option<u8>,
     pub reports_to: Option<EntityId<Role>>,
+    #[serde(default)]
     pub permissions: Vec<String>,
+    #[serde(default)]
     pub responsibilities: Vec<String>,
+    #[serde(default)]
     pub status: RoleStatus,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -160,12 +298,14 @@ impl DomainEntity for Role {
 }
 
 /// Role types
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum RoleType {
     Executive,
     Management,
     Technical,
     Administrative,
+    /// Assumed for historical roles recorded without a type
+    #[default]
     Operational,
     Support,
     Contractor,
@@ -173,8 +313,9 @@ pub enum RoleType {
 }
 
 /// Role status
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum RoleStatus {
+    #[default]
     Active,
     Vacant,
     Deprecated,
@@ -274,6 +415,27 @@ impl Organization {
     pub fn builder(name: String) -> OrganizationBuilder {
         OrganizationBuilder::new(name)
     }
+
+    /// Whether this is a top-level organization rather than a sub-unit
+    pub fn is_root(&self) -> bool {
+        self.parent_id.is_none()
+    }
+
+    /// Age of the organization in whole years as of the given date
+    ///
+    /// Returns `None` when no founding date is known or `as_of` precedes it.
+    pub fn age_years(&self, as_of: NaiveDate) -> Option<u32> {
+        let founded = self.founded_date?.date_naive();
+        if as_of < founded {
+            return None;
+        }
+
+        let mut years = as_of.year() - founded.year();
+        if (as_of.month(), as_of.day()) < (founded.month(), founded.day()) {
+            years -= 1;
+        }
+        u32::try_from(years).ok()
+    }
 }
 
 pub struct OrganizationBuilder {
@@ -362,6 +524,7 @@ impl Department {
             code,
             description: None,
             head_role_id: None,
+            default_permissions: Vec::new(),
             status: DepartmentStatus::Active,
             created_at: now,
             updated_at: now,
@@ -418,6 +581,11 @@ impl Role {
             updated_at: now,
         }
     }
+
+    /// Check whether this role grants the given permission
+    pub fn has_permission(&self, permission: &crate::aggregate::Permission) -> bool {
+        self.permissions.iter().any(|p| p == permission.as_str())
+    }
 }
 
 impl Facility {
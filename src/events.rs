@@ -8,6 +8,7 @@ use cim_domain::{
     EntityId,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use uuid::Uuid;
 
 use crate::entity::{
@@ -24,6 +25,7 @@ use crate::entity::{
 pub enum OrganizationEvent {
     OrganizationCreated(OrganizationCreated),
     OrganizationUpdated(OrganizationUpdated),
+    OrganizationMetadataUpdated(OrganizationMetadataUpdated),
     OrganizationDissolved(OrganizationDissolved),
     OrganizationMerged(OrganizationMerged),
     OrganizationStatusChanged(OrganizationStatusChanged),
@@ -42,6 +44,8 @@ pub enum OrganizationEvent {
     FacilityRemoved(FacilityRemoved),
     ChildOrganizationAdded(ChildOrganizationAdded),
     ChildOrganizationRemoved(ChildOrganizationRemoved),
+    OrganizationTagAdded(OrganizationTagAdded),
+    OrganizationTagRemoved(OrganizationTagRemoved),
 }
 
 impl cim_domain::DomainEvent for OrganizationEvent {
@@ -49,6 +53,7 @@ impl cim_domain::DomainEvent for OrganizationEvent {
         match self {
             OrganizationEvent::OrganizationCreated(e) => e.organization_id.clone().into(),
             OrganizationEvent::OrganizationUpdated(e) => e.organization_id.clone().into(),
+            OrganizationEvent::OrganizationMetadataUpdated(e) => e.organization_id.clone().into(),
             OrganizationEvent::OrganizationDissolved(e) => e.organization_id.clone().into(),
             OrganizationEvent::OrganizationMerged(e) => e.surviving_organization_id.clone().into(),
             OrganizationEvent::OrganizationStatusChanged(e) => e.organization_id.clone().into(),
@@ -67,6 +72,8 @@ impl cim_domain::DomainEvent for OrganizationEvent {
             OrganizationEvent::FacilityRemoved(e) => e.organization_id.clone().into(),
             OrganizationEvent::ChildOrganizationAdded(e) => e.parent_organization_id.clone().into(),
             OrganizationEvent::ChildOrganizationRemoved(e) => e.parent_organization_id.clone().into(),
+            OrganizationEvent::OrganizationTagAdded(e) => e.organization_id.clone().into(),
+            OrganizationEvent::OrganizationTagRemoved(e) => e.organization_id.clone().into(),
         }
     }
 
@@ -74,6 +81,7 @@ impl cim_domain::DomainEvent for OrganizationEvent {
         match self {
             OrganizationEvent::OrganizationCreated(_) => "OrganizationCreated",
             OrganizationEvent::OrganizationUpdated(_) => "OrganizationUpdated",
+            OrganizationEvent::OrganizationMetadataUpdated(_) => "OrganizationMetadataUpdated",
             OrganizationEvent::OrganizationDissolved(_) => "OrganizationDissolved",
             OrganizationEvent::OrganizationMerged(_) => "OrganizationMerged",
             OrganizationEvent::OrganizationStatusChanged(_) => "OrganizationStatusChanged",
@@ -92,10 +100,263 @@ impl cim_domain::DomainEvent for OrganizationEvent {
             OrganizationEvent::FacilityRemoved(_) => "FacilityRemoved",
             OrganizationEvent::ChildOrganizationAdded(_) => "ChildOrganizationAdded",
             OrganizationEvent::ChildOrganizationRemoved(_) => "ChildOrganizationRemoved",
+            OrganizationEvent::OrganizationTagAdded(_) => "OrganizationTagAdded",
+            OrganizationEvent::OrganizationTagRemoved(_) => "OrganizationTagRemoved",
         }
     }
 }
 
+impl OrganizationEvent {
+    /// Short human-readable description for logs and timelines
+    pub fn summary(&self) -> String {
+        match self {
+            OrganizationEvent::OrganizationCreated(e) => {
+                format!("Organization '{}' created as {:?}", e.name, e.organization_type)
+            }
+            OrganizationEvent::OrganizationUpdated(e) => match &e.changes.name {
+                Some(name) => format!("Organization renamed to '{}'", name),
+                None => "Organization details updated".to_string(),
+            },
+            OrganizationEvent::OrganizationMetadataUpdated(_) => "Organization metadata updated".to_string(),
+            OrganizationEvent::OrganizationDissolved(e) => {
+                format!("Organization dissolved: {}", e.reason)
+            }
+            OrganizationEvent::OrganizationMerged(e) => format!(
+                "Organization {} merged into {} ({:?})",
+                e.merged_organization_id, e.surviving_organization_id, e.merger_type
+            ),
+            OrganizationEvent::OrganizationStatusChanged(e) => {
+                format!("Status changed {:?}→{:?}", e.previous_status, e.new_status)
+            }
+            OrganizationEvent::DepartmentCreated(e) => {
+                format!("Department '{}' ({}) created", e.name, e.code)
+            }
+            OrganizationEvent::DepartmentUpdated(e) => format!("Department {} updated", e.department_id),
+            OrganizationEvent::DepartmentRestructured(e) => {
+                format!("Department {} restructured ({:?})", e.department_id, e.restructure_type)
+            }
+            OrganizationEvent::DepartmentDissolved(e) => {
+                format!("Department {} dissolved: {}", e.department_id, e.reason)
+            }
+            OrganizationEvent::TeamFormed(e) => format!("Team '{}' formed ({:?})", e.name, e.team_type),
+            OrganizationEvent::TeamUpdated(e) => format!("Team {} updated", e.team_id),
+            OrganizationEvent::TeamDisbanded(e) => format!("Team {} disbanded: {}", e.team_id, e.reason),
+            OrganizationEvent::RoleCreated(e) => format!("Role '{}' ({}) created", e.title, e.code),
+            OrganizationEvent::RoleUpdated(e) => match &e.changes.title {
+                Some(title) => format!("Role {} retitled to '{}'", e.role_id, title),
+                None => format!("Role {} updated", e.role_id),
+            },
+            OrganizationEvent::RoleDeprecated(e) => format!("Role {} deprecated: {}", e.role_id, e.reason),
+            OrganizationEvent::FacilityCreated(e) => {
+                format!("Facility '{}' ({}) created as {:?}", e.name, e.code, e.facility_type)
+            }
+            OrganizationEvent::FacilityUpdated(e) => format!("Facility {} updated", e.facility_id),
+            OrganizationEvent::FacilityRemoved(e) => format!("Facility {} removed", e.facility_id),
+            OrganizationEvent::ChildOrganizationAdded(e) => {
+                format!("Child organization '{}' added", e.child_name)
+            }
+            OrganizationEvent::ChildOrganizationRemoved(e) => {
+                format!("Child organization {} removed", e.child_organization_id)
+            }
+            OrganizationEvent::OrganizationTagAdded(e) => format!("Tag '{}' added", e.tag),
+            OrganizationEvent::OrganizationTagRemoved(e) => format!("Tag '{}' removed", e.tag),
+        }
+    }
+
+    /// Shard key for partitioned event stores
+    ///
+    /// The aggregate id, so one organization's events land on one shard and
+    /// keep their order. `OrganizationMerged` keys to the surviving
+    /// organization, the only one it changes; the merged organization's own
+    /// move to `Acquired` or `Merged` is an `OrganizationStatusChanged` keyed
+    /// to it (see `CompleteMerge`). Child organization events key to the
+    /// parent, whose stream records them.
+    pub fn partition_key(&self) -> Uuid {
+        cim_domain::DomainEvent::aggregate_id(self)
+    }
+
+    /// Identity of the message that produced the event
+    pub fn identity(&self) -> &MessageIdentity {
+        match self {
+            OrganizationEvent::OrganizationCreated(e) => &e.identity,
+            OrganizationEvent::OrganizationUpdated(e) => &e.identity,
+            OrganizationEvent::OrganizationMetadataUpdated(e) => &e.identity,
+            OrganizationEvent::OrganizationDissolved(e) => &e.identity,
+            OrganizationEvent::OrganizationMerged(e) => &e.identity,
+            OrganizationEvent::OrganizationStatusChanged(e) => &e.identity,
+            OrganizationEvent::DepartmentCreated(e) => &e.identity,
+            OrganizationEvent::DepartmentUpdated(e) => &e.identity,
+            OrganizationEvent::DepartmentRestructured(e) => &e.identity,
+            OrganizationEvent::DepartmentDissolved(e) => &e.identity,
+            OrganizationEvent::TeamFormed(e) => &e.identity,
+            OrganizationEvent::TeamUpdated(e) => &e.identity,
+            OrganizationEvent::TeamDisbanded(e) => &e.identity,
+            OrganizationEvent::RoleCreated(e) => &e.identity,
+            OrganizationEvent::RoleUpdated(e) => &e.identity,
+            OrganizationEvent::RoleDeprecated(e) => &e.identity,
+            OrganizationEvent::FacilityCreated(e) => &e.identity,
+            OrganizationEvent::FacilityUpdated(e) => &e.identity,
+            OrganizationEvent::FacilityRemoved(e) => &e.identity,
+            OrganizationEvent::ChildOrganizationAdded(e) => &e.identity,
+            OrganizationEvent::ChildOrganizationRemoved(e) => &e.identity,
+            OrganizationEvent::OrganizationTagAdded(e) => &e.identity,
+            OrganizationEvent::OrganizationTagRemoved(e) => &e.identity,
+        }
+    }
+
+    /// When the event was recorded
+    pub fn occurred_at(&self) -> DateTime<Utc> {
+        match self {
+            OrganizationEvent::OrganizationCreated(e) => e.occurred_at,
+            OrganizationEvent::OrganizationUpdated(e) => e.occurred_at,
+            OrganizationEvent::OrganizationMetadataUpdated(e) => e.occurred_at,
+            OrganizationEvent::OrganizationDissolved(e) => e.occurred_at,
+            OrganizationEvent::OrganizationMerged(e) => e.occurred_at,
+            OrganizationEvent::OrganizationStatusChanged(e) => e.occurred_at,
+            OrganizationEvent::DepartmentCreated(e) => e.occurred_at,
+            OrganizationEvent::DepartmentUpdated(e) => e.occurred_at,
+            OrganizationEvent::DepartmentRestructured(e) => e.occurred_at,
+            OrganizationEvent::DepartmentDissolved(e) => e.occurred_at,
+            OrganizationEvent::TeamFormed(e) => e.occurred_at,
+            OrganizationEvent::TeamUpdated(e) => e.occurred_at,
+            OrganizationEvent::TeamDisbanded(e) => e.occurred_at,
+            OrganizationEvent::RoleCreated(e) => e.occurred_at,
+            OrganizationEvent::RoleUpdated(e) => e.occurred_at,
+            OrganizationEvent::RoleDeprecated(e) => e.occurred_at,
+            OrganizationEvent::FacilityCreated(e) => e.occurred_at,
+            OrganizationEvent::FacilityUpdated(e) => e.occurred_at,
+            OrganizationEvent::FacilityRemoved(e) => e.occurred_at,
+            OrganizationEvent::ChildOrganizationAdded(e) => e.occurred_at,
+            OrganizationEvent::ChildOrganizationRemoved(e) => e.occurred_at,
+            OrganizationEvent::OrganizationTagAdded(e) => e.occurred_at,
+            OrganizationEvent::OrganizationTagRemoved(e) => e.occurred_at,
+        }
+    }
+
+    /// Check that the causation links in an event sequence are well formed
+    ///
+    /// Events are grouped by correlation id, in stream order. An event is a
+    /// root when its causation id is its own message id; otherwise the cause
+    /// must be the message id of an earlier event with the same correlation.
+    /// Returns the chains when every link resolves, or every broken link.
+    pub fn causation_chains(events: &[OrganizationEvent]) -> Result<Vec<CausationChain>, Vec<CausationIssue>> {
+        let mut chains: Vec<CausationChain> = Vec::new();
+        let mut seen_messages: HashMap<Uuid, Uuid> = HashMap::new();
+        let mut issues = Vec::new();
+
+        for (event_index, event) in events.iter().enumerate() {
+            let identity = event.identity();
+            let correlation_id = correlation_uuid(&identity.correlation_id);
+            let causation_id = identity.causation_id.0;
+
+            if causation_id != identity.message_id {
+                match seen_messages.get(&causation_id) {
+                    None => issues.push(CausationIssue::UnknownCause { event_index, causation_id }),
+                    Some(cause_correlation_id) if *cause_correlation_id != correlation_id => {
+                        issues.push(CausationIssue::CrossCorrelation {
+                            event_index,
+                            cause_correlation_id: *cause_correlation_id,
+                        });
+                    }
+                    Some(_) => {}
+                }
+            }
+            seen_messages.entry(identity.message_id).or_insert(correlation_id);
+
+            match chains.iter_mut().find(|chain| chain.correlation_id == correlation_id) {
+                Some(chain) => chain.event_indices.push(event_index),
+                None => chains.push(CausationChain {
+                    correlation_id,
+                    event_indices: vec![event_index],
+                }),
+            }
+        }
+
+        if issues.is_empty() {
+            Ok(chains)
+        } else {
+            Err(issues)
+        }
+    }
+
+    /// Copy of the event safe for audit exports and analytics
+    ///
+    /// Free-text reasons and organization metadata are where personal data
+    /// ends up, so they are cleared; ids, types and timestamps are kept so
+    /// the event can still be joined and aggregated. Events carry no person
+    /// ids in this domain, so nothing needs hashing.
+    pub fn redacted(&self) -> OrganizationEvent {
+        let mut event = self.clone();
+        match &mut event {
+            OrganizationEvent::OrganizationCreated(e) => {
+                e.metadata = serde_json::Value::Object(serde_json::Map::new());
+            }
+            OrganizationEvent::OrganizationUpdated(e) => {
+                if let Some(metadata) = &mut e.changes.metadata {
+                    *metadata = serde_json::Value::Object(serde_json::Map::new());
+                }
+            }
+            OrganizationEvent::OrganizationMetadataUpdated(e) => {
+                e.patch = serde_json::Value::Object(serde_json::Map::new());
+            }
+            OrganizationEvent::OrganizationDissolved(e) => e.reason.clear(),
+            OrganizationEvent::DepartmentDissolved(e) => e.reason.clear(),
+            OrganizationEvent::TeamDisbanded(e) => e.reason.clear(),
+            OrganizationEvent::RoleDeprecated(e) => e.reason.clear(),
+            OrganizationEvent::FacilityRemoved(e) => e.reason = None,
+            OrganizationEvent::OrganizationStatusChanged(e) => e.reason = None,
+            _ => {}
+        }
+        event
+    }
+}
+
+/// Events of one logical flow, sharing a correlation id
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CausationChain {
+    pub correlation_id: Uuid,
+    /// Positions of the flow's events in the checked slice, in order
+    pub event_indices: Vec<usize>,
+}
+
+/// A broken link found by `OrganizationEvent::causation_chains`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CausationIssue {
+    /// The cause is not the message id of any earlier event
+    UnknownCause { event_index: usize, causation_id: Uuid },
+    /// The cause belongs to a different correlation
+    CrossCorrelation { event_index: usize, cause_correlation_id: Uuid },
+}
+
+/// Uuid behind a correlation id, whichever form it takes
+fn correlation_uuid(correlation_id: &cim_domain::CorrelationId) -> Uuid {
+    match correlation_id {
+        cim_domain::CorrelationId::Single(id) => *id,
+        cim_domain::CorrelationId::Transaction(id) => id.0,
+    }
+}
+
+/// Compact binary encoding for event stores (MessagePack)
+///
+/// Structs are written as maps keyed by field name and enum variants by
+/// variant name, never by position. Adding fields (with `#[serde(default)]`)
+/// or adding and reordering variants therefore keeps old bytes readable;
+/// renaming a variant or field does not.
+#[cfg(feature = "binary")]
+impl OrganizationEvent {
+    /// Encode the event as MessagePack
+    pub fn to_bytes(&self) -> crate::OrganizationResult<Vec<u8>> {
+        rmp_serde::to_vec_named(self)
+            .map_err(|e| crate::OrganizationError::Serialization(e.to_string()))
+    }
+
+    /// Decode an event written by `to_bytes`
+    pub fn from_bytes(bytes: &[u8]) -> crate::OrganizationResult<Self> {
+        rmp_serde::from_slice(bytes)
+            .map_err(|e| crate::OrganizationError::Serialization(e.to_string()))
+    }
+}
+
 // Organization lifecycle events
 
 /// Event: Organization created
@@ -108,6 +369,8 @@ pub struct OrganizationCreated {
     pub display_name: String,
     pub organization_type: OrganizationType,
     pub parent_id: Option<EntityId<Organization>>,
+    #[serde(default)]
+    pub founded_date: Option<DateTime<Utc>>,
     pub metadata: serde_json::Value,
     pub occurred_at: DateTime<Utc>,
 }
@@ -133,6 +396,19 @@ pub struct OrganizationChanges {
     pub metadata: Option<serde_json::Value>,
 }
 
+/// Event: Organization metadata patched
+///
+/// Carries the JSON merge patch rather than the resulting metadata, so the
+/// merge is replayed when the event is applied.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrganizationMetadataUpdated {
+    pub event_id: Uuid,
+    pub identity: MessageIdentity,
+    pub organization_id: EntityId<Organization>,
+    pub patch: serde_json::Value,
+    pub occurred_at: DateTime<Utc>,
+}
+
 
 
 /// Event: Organization dissolved
@@ -157,6 +433,12 @@ pub struct OrganizationMerged {
     pub merged_organization_id: EntityId<Organization>,
     pub merger_type: MergerType,
     pub effective_date: DateTime<Utc>,
+    /// Name of the merged organization, recorded as the survivor's child after an acquisition
+    #[serde(default)]
+    pub merged_organization_name: Option<String>,
+    /// Type of the merged organization
+    #[serde(default)]
+    pub merged_organization_type: Option<OrganizationType>,
     pub occurred_at: DateTime<Utc>,
 }
 
@@ -182,6 +464,11 @@ pub struct DepartmentCreated {
     pub parent_department_id: Option<EntityId<Department>>,
     pub name: String,
     pub code: String,
+    #[serde(default)]
+    pub default_permissions: Vec<String>,
+    /// Head role created in the same command, see `CreateDepartment::seed_head_role`
+    #[serde(default)]
+    pub head_role_id: Option<EntityId<Role>>,
     pub occurred_at: DateTime<Utc>,
 }
 
@@ -311,12 +598,18 @@ pub struct RoleCreated {
     pub department_id: Option<EntityId<Department>>,
     pub team_id: Option<EntityId<Team>>,
     pub title: String,
+    // Fields below may be absent from early streams; keep them defaulted
+    // so those streams still replay
+    #[serde(default)]
     pub code: String,
     pub description: Option<String>,
+    #[serde(default)]
     pub role_type: RoleType,
     pub level: Option<u8>,
     pub reports_to: Option<EntityId<Role>>,
+    #[serde(default)]
     pub permissions: Vec<String>,
+    #[serde(default)]
     pub responsibilities: Vec<String>,
     pub occurred_at: DateTime<Utc>,
 }
@@ -331,6 +624,12 @@ pub struct RoleUpdated {
     pub role_id: EntityId<Role>,
     pub organization_id: EntityId<Organization>,
     pub changes: RoleChanges,
+    /// Effective permissions the role gained, for access provisioning
+    #[serde(default)]
+    pub gained_permissions: Vec<String>,
+    /// Effective permissions the role lost
+    #[serde(default)]
+    pub lost_permissions: Vec<String>,
     pub occurred_at: DateTime<Utc>,
 }
 
@@ -338,6 +637,8 @@ pub struct RoleUpdated {
 pub struct RoleChanges {
     pub title: Option<String>,
     pub description: Option<String>,
+    #[serde(default)]
+    pub role_type: Option<RoleType>,
     pub level: Option<u8>,
     pub reports_to: Option<EntityId<Role>>,
     pub permissions: Option<Vec<String>>,
@@ -425,6 +726,10 @@ pub struct OrganizationStatusChanged {
     pub new_status: crate::entity::OrganizationStatus,
     pub previous_status: crate::entity::OrganizationStatus,
     pub reason: Option<String>,
+    /// Organization this one becomes a subsidiary of, set when an acquisition
+    /// completes; see `CompleteMerge`
+    #[serde(default)]
+    pub parent_id: Option<EntityId<Organization>>,
     pub occurred_at: DateTime<Utc>,
 }
 
@@ -450,4 +755,22 @@ pub struct ChildOrganizationRemoved {
     pub occurred_at: DateTime<Utc>,
 }
 
+/// Event: Tag added to organization
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrganizationTagAdded {
+    pub event_id: Uuid,
+    pub identity: MessageIdentity,
+    pub organization_id: EntityId<Organization>,
+    pub tag: String,
+    pub occurred_at: DateTime<Utc>,
+}
 
+/// Event: Tag removed from organization
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrganizationTagRemoved {
+    pub event_id: Uuid,
+    pub identity: MessageIdentity,
+    pub organization_id: EntityId<Organization>,
+    pub tag: String,
+    pub occurred_at: DateTime<Utc>,
+}
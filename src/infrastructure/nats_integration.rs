@@ -1,15 +1,16 @@
 //! NATS integration for Organization domain
 
 use async_nats::{Client, jetstream};
+use async_trait::async_trait;
 use cim_domain::{DomainResult, Command};
 use std::sync::Arc;
 use futures::StreamExt;
 use tracing::{info, error, warn};
 use uuid::Uuid;
 
-use crate::aggregate::OrganizationAggregate;
 use crate::events::OrganizationEvent;
 use crate::commands::OrganizationCommand;
+use crate::ports::EventStore;
 use crate::OrganizationError;
 use super::persistence::OrganizationRepository;
 
@@ -99,9 +100,12 @@ impl NatsEventStore {
             stream_name,
         })
     }
+}
 
+#[async_trait]
+impl EventStore for NatsEventStore {
     /// Append events to the stream
-    pub async fn append_events(
+    async fn append_events(
         &self,
         aggregate_id: Uuid,
         events: Vec<OrganizationEvent>,
@@ -111,6 +115,7 @@ impl NatsEventStore {
             let event_type = match &event {
                 OrganizationEvent::OrganizationCreated(_) => "created",
                 OrganizationEvent::OrganizationUpdated(_) => "updated",
+                OrganizationEvent::OrganizationMetadataUpdated(_) => "metadata_updated",
                 OrganizationEvent::OrganizationStatusChanged(_) => "status_changed",
                 OrganizationEvent::OrganizationDissolved(_) => "dissolved",
                 OrganizationEvent::OrganizationMerged(_) => "merged",
@@ -129,6 +134,8 @@ impl NatsEventStore {
                 OrganizationEvent::FacilityRemoved(_) => "facility_removed",
                 OrganizationEvent::ChildOrganizationAdded(_) => "child_added",
                 OrganizationEvent::ChildOrganizationRemoved(_) => "child_removed",
+                OrganizationEvent::OrganizationTagAdded(_) => "tag_added",
+                OrganizationEvent::OrganizationTagRemoved(_) => "tag_removed",
             };
 
             let subject = OrganizationSubjects::event_for(aggregate_id, event_type);
@@ -209,26 +216,23 @@ impl OrganizationCommandHandler {
 
     /// Handle a single command
     async fn handle_command(&self, command: OrganizationCommand) -> Result<(), OrganizationError> {
-        // Get aggregate ID from command
-        let aggregate_id = command.aggregate_id()
-            .map(|id| id.into())
-            .unwrap_or_else(|| Uuid::now_v7());
-
-        // Get or create aggregate
-        let mut aggregate = self.repository
-            .get(aggregate_id)
-            .await
-            .unwrap_or_else(|_| OrganizationAggregate::new(
-                aggregate_id,
-                "New Organization".to_string(),
-                crate::entity::OrganizationType::Corporation,
-            ));
-
-        // Handle command
-        let events = aggregate.handle_command(command)?;
-
-        // Save events
-        self.repository.save(aggregate_id, events).await?;
+        let follow_ups = self.repository.execute(command).await?;
+
+        // Child and merged organizations are separate aggregates; hand them their commands
+        for follow_up in follow_ups {
+            let target_id: Uuid = match follow_up.aggregate_id() {
+                Some(id) => id.into(),
+                None => continue,
+            };
+            let payload = serde_json::to_vec(&follow_up)
+                .map_err(|e| cim_domain::DomainError::SerializationError(e.to_string()))?;
+            if let Err(e) = self.client
+                .publish(OrganizationSubjects::command_for(target_id), payload.into())
+                .await
+            {
+                warn!("Failed to dispatch follow-up command to {}: {}", target_id, e);
+            }
+        }
 
         Ok(())
     }
@@ -1,19 +1,26 @@
 //! Persistence layer for Organization domain
 
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use cim_domain::{Command, DomainEvent, DomainResult};
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 use uuid::Uuid;
 
 use crate::aggregate::OrganizationAggregate;
+use crate::commands::OrganizationCommand;
 use crate::events::OrganizationEvent;
+use crate::ports::EventStore;
 use crate::OrganizationResult;
-use super::nats_integration::NatsEventStore;
 
 /// Snapshot for OrganizationAggregate
 #[derive(Clone, Debug)]
 pub struct OrganizationSnapshot {
     pub aggregate: OrganizationAggregate,
     pub version: u64,
+    /// Time of the last event in the snapshot, for staleness checks;
+    /// `None` when the aggregate predates timestamp tracking
+    pub updated_at: Option<DateTime<Utc>>,
 }
 
 /// In-memory snapshot store
@@ -46,16 +53,50 @@ impl Default for InMemorySnapshotStore {
     }
 }
 
+/// In-memory event store, one stream per aggregate
+pub struct InMemoryEventStore {
+    streams: RwLock<HashMap<Uuid, Vec<OrganizationEvent>>>,
+}
+
+impl InMemoryEventStore {
+    pub fn new() -> Self {
+        Self {
+            streams: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Events appended to the aggregate's stream, oldest first
+    pub fn events(&self, aggregate_id: Uuid) -> Vec<OrganizationEvent> {
+        let streams = self.streams.read().unwrap();
+        streams.get(&aggregate_id).cloned().unwrap_or_default()
+    }
+}
+
+impl Default for InMemoryEventStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl EventStore for InMemoryEventStore {
+    async fn append_events(&self, aggregate_id: Uuid, events: Vec<OrganizationEvent>) -> DomainResult<()> {
+        let mut streams = self.streams.write().unwrap();
+        streams.entry(aggregate_id).or_default().extend(events);
+        Ok(())
+    }
+}
+
 /// Repository for OrganizationAggregate
 pub struct OrganizationRepository {
-    event_store: Arc<NatsEventStore>,
+    event_store: Arc<dyn EventStore>,
     snapshot_store: Arc<InMemorySnapshotStore>,
     snapshot_frequency: u64,
 }
 
 impl OrganizationRepository {
     pub fn new(
-        event_store: Arc<NatsEventStore>,
+        event_store: Arc<dyn EventStore>,
         snapshot_store: Arc<InMemorySnapshotStore>,
         snapshot_frequency: u64,
     ) -> Self {
@@ -70,6 +111,8 @@ impl OrganizationRepository {
     pub async fn get(&self, aggregate_id: Uuid) -> OrganizationResult<OrganizationAggregate> {
         // Try to load from snapshot first
         if let Some(snapshot) = self.snapshot_store.get(aggregate_id) {
+            // Snapshots bypass command validation, so re-check the reporting graph
+            snapshot.aggregate.validate_reporting_acyclic()?;
             // TODO: Load events after snapshot and replay
             return Ok(snapshot.aggregate);
         }
@@ -81,6 +124,87 @@ impl OrganizationRepository {
         ))
     }
 
+    /// Handle a command on its aggregate and save the resulting events
+    ///
+    /// Returns the commands meant for other aggregates, for the caller to
+    /// dispatch: cascades to child organizations and, after a merge, the
+    /// merged organization's `CompleteMerge`.
+    pub async fn execute(&self, command: OrganizationCommand) -> OrganizationResult<Vec<OrganizationCommand>> {
+        let aggregate_id = command.aggregate_id()
+            .map(|id| id.into())
+            .unwrap_or_else(Uuid::now_v7);
+
+        // Get or create aggregate
+        let mut aggregate = self.get(aggregate_id).await.unwrap_or_else(|_| {
+            OrganizationAggregate::new(
+                aggregate_id,
+                "New Organization".to_string(),
+                crate::entity::OrganizationType::Corporation,
+            )
+        });
+
+        let mut follow_ups = aggregate.cascade_to_children(&command);
+        follow_ups.extend(aggregate.merged_party_command(&command));
+        aggregate.execute(command)?;
+
+        self.save(aggregate_id, aggregate.take_uncommitted()).await?;
+        Ok(follow_ups)
+    }
+
+    /// Get the aggregate as it was after its first `version` events
+    ///
+    /// The event store cannot read streams back yet, so the caller passes the
+    /// organization's stream in `events`. A snapshot at or below `version`
+    /// is used as the starting point, and only the events after it are
+    /// replayed; without one the whole prefix is replayed. `events` must
+    /// start with the organization's `OrganizationCreated` event and hold no
+    /// events of other aggregates.
+    pub async fn load_at_version(
+        &self,
+        aggregate_id: Uuid,
+        events: &[OrganizationEvent],
+        version: u64,
+    ) -> OrganizationResult<OrganizationAggregate> {
+        if let Some(first) = events.first() {
+            if !matches!(first, OrganizationEvent::OrganizationCreated(_)) {
+                return Err(crate::OrganizationError::InvalidStructure(format!(
+                    "Stream for organization {} does not start with OrganizationCreated",
+                    aggregate_id
+                )));
+            }
+        }
+        if let Some(foreign) = events.iter().find(|event| event.aggregate_id() != aggregate_id) {
+            return Err(crate::OrganizationError::InvalidStructure(format!(
+                "Stream for organization {} contains {} of organization {}",
+                aggregate_id,
+                foreign.event_type(),
+                foreign.aggregate_id()
+            )));
+        }
+
+        let snapshot = self
+            .snapshot_store
+            .get(aggregate_id)
+            .filter(|snapshot| snapshot.version <= version);
+
+        let Some(snapshot) = snapshot else {
+            return OrganizationAggregate::replay_until(events, version);
+        };
+
+        let available = (events.len() as u64).max(snapshot.version);
+        if version > available {
+            return Err(crate::OrganizationError::VersionOutOfRange { requested: version, available });
+        }
+
+        // Snapshots bypass command validation, so re-check the reporting graph
+        snapshot.aggregate.validate_reporting_acyclic()?;
+        let mut aggregate = snapshot.aggregate;
+        for sequence in snapshot.version + 1..=version {
+            aggregate.apply_event_checked(&events[sequence as usize - 1], sequence)?;
+        }
+        Ok(aggregate)
+    }
+
     /// Save events and update aggregate
     pub async fn save(
         &self,
@@ -115,6 +239,7 @@ impl OrganizationRepository {
             let snapshot = OrganizationSnapshot {
                 aggregate: aggregate.clone(),
                 version: aggregate.version,
+                updated_at: aggregate.updated_at,
             };
             self.snapshot_store.save(aggregate_id, snapshot)?;
         }
@@ -11,34 +11,41 @@ pub mod nats;
 pub mod ports;
 pub mod adapters;
 pub mod infrastructure;
+pub mod prelude;
+pub mod role_templates;
 
 // Re-export main types
 pub use entity::{
     Organization, Department, Team, Role, Facility,
-    OrganizationType, OrganizationStatus,
+    OrganizationType, OrganizationStatus, DisplayMetadata,
     OrganizationUnit, FacilityType, FacilityStatus,
     RoleType, RoleStatus, DepartmentStatus, TeamStatus, TeamType
 };
 pub use aggregate::{
-    OrganizationAggregate, Permission, OrganizationState
+    OrganizationAggregate, Permission, OrganizationState,
+    LayerAssessment, ManagementLayerReport, StructureRules, StructureWarning,
+    ChainExportFormat, ReportingChange
 };
 pub use events::{
-    OrganizationEvent, OrganizationCreated, OrganizationUpdated,
+    OrganizationEvent, OrganizationCreated, OrganizationUpdated, OrganizationMetadataUpdated,
     OrganizationStatusChanged, OrganizationDissolved, OrganizationMerged,
     DepartmentCreated, DepartmentUpdated, DepartmentRestructured, DepartmentDissolved,
     TeamFormed, TeamUpdated, TeamDisbanded,
     RoleCreated, RoleUpdated, RoleDeprecated,
     FacilityCreated, FacilityUpdated, FacilityRemoved,
-    ChildOrganizationAdded, ChildOrganizationRemoved
+    ChildOrganizationAdded, ChildOrganizationRemoved,
+    OrganizationTagAdded, OrganizationTagRemoved,
+    CausationChain, CausationIssue
 };
 pub use commands::{
-    OrganizationCommand, CreateOrganization, UpdateOrganization,
-    DissolveOrganization, MergeOrganizations, ChangeOrganizationStatus,
+    OrganizationCommand, CreateOrganization, UpdateOrganization, UpdateOrganizationMetadata,
+    DissolveOrganization, MergeOrganizations, MergeCounterpart, CompleteMerge, ChangeOrganizationStatus,
     CreateDepartment, UpdateDepartment, RestructureDepartment, DissolveDepartment,
     CreateTeam, UpdateTeam, DisbandTeam,
-    CreateRole, UpdateRole, DeprecateRole,
+    CreateRole, UpdateRole, DeprecateRole, SwapReporting, RenameRoleTitle,
     CreateFacility, UpdateFacility, RemoveFacility,
-    AddChildOrganization, RemoveChildOrganization
+    AddChildOrganization, RemoveChildOrganization,
+    AddOrganizationTag, RemoveOrganizationTag
 };
 pub use cim_domain::{EntityId, MessageIdentity};
 
@@ -69,6 +76,21 @@ pub enum OrganizationError {
     #[error("Circular reference: {0}")]
     CircularReference(String),
 
+    #[error("Invalid status: {0}")]
+    InvalidStatus(String),
+
+    #[error("Event out of sequence: expected {expected}, got {actual}")]
+    EventOutOfSequence { expected: u64, actual: u64 },
+
+    #[error("Version {requested} exceeds stream length {available}")]
+    VersionOutOfRange { requested: u64, available: u64 },
+
+    #[error("Invalid merge: {0}")]
+    InvalidMerge(String),
+
+    #[error("Serialization error: {0}")]
+    Serialization(String),
+
     #[error("Domain error: {0}")]
     DomainError(#[from] DomainError),
 }
@@ -4,6 +4,8 @@
 
 use cim_domain::{Subject, SubjectError};
 
+pub mod subjects;
+
 /// Base subject prefix for organization domain
 pub const ORGANIZATION_DOMAIN: &str = "organization";
 
@@ -23,6 +23,8 @@ use std::collections::HashMap;
 use std::fmt::{self, Display, Formatter};
 use uuid::Uuid;
 
+use super::ORGANIZATION_DOMAIN;
+
 /// Core organization domain subject patterns following CIM Subject Algebra.
 /// 
 /// Subject Structure: `events.organization.{aggregate}.{scope}.{operation}.{entity_id}`
@@ -32,7 +34,7 @@ use uuid::Uuid;
 /// - scope: Global, Organization, Department, Team, Individual
 /// - operation: CRUD + domain-specific operations
 /// - entity_id: Specific entity identifier (optional for queries)
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct OrganizationSubject {
     /// Optional namespace for multi-tenant deployments
     pub namespace: Option<String>,
@@ -173,6 +175,100 @@ pub enum OrganizationScope {
     Vendor(Uuid),
 }
 
+impl OrganizationSubjectRoot {
+    /// Every root, in declaration order
+    pub const ALL: [OrganizationSubjectRoot; 8] = [
+        OrganizationSubjectRoot::Events,
+        OrganizationSubjectRoot::Commands,
+        OrganizationSubjectRoot::Queries,
+        OrganizationSubjectRoot::Workflows,
+        OrganizationSubjectRoot::System,
+        OrganizationSubjectRoot::Analytics,
+        OrganizationSubjectRoot::Compliance,
+        OrganizationSubjectRoot::Integration,
+    ];
+    
+    /// Whether `token` is the subject token of some root
+    pub fn is_root_token(token: &str) -> bool {
+        Self::ALL.iter().any(|root| root.token() == token)
+    }
+    
+    /// Subject token for this root
+    pub fn token(&self) -> &'static str {
+        match self {
+            OrganizationSubjectRoot::Events => "events",
+            OrganizationSubjectRoot::Commands => "commands",
+            OrganizationSubjectRoot::Queries => "queries",
+            OrganizationSubjectRoot::Workflows => "workflows",
+            OrganizationSubjectRoot::System => "system",
+            OrganizationSubjectRoot::Analytics => "analytics",
+            OrganizationSubjectRoot::Compliance => "compliance",
+            OrganizationSubjectRoot::Integration => "integration",
+        }
+    }
+}
+
+impl OrganizationAggregate {
+    /// Every aggregate type, in declaration order
+    pub const ALL: [OrganizationAggregate; 15] = [
+        OrganizationAggregate::Organization,
+        OrganizationAggregate::Department,
+        OrganizationAggregate::Team,
+        OrganizationAggregate::Role,
+        OrganizationAggregate::Policy,
+        OrganizationAggregate::Resource,
+        OrganizationAggregate::Structure,
+        OrganizationAggregate::Culture,
+        OrganizationAggregate::Strategy,
+        OrganizationAggregate::Performance,
+        OrganizationAggregate::Communication,
+        OrganizationAggregate::Change,
+        OrganizationAggregate::Risk,
+        OrganizationAggregate::Vendor,
+        OrganizationAggregate::Location,
+    ];
+
+    /// Subject token for this aggregate
+    pub fn token(&self) -> &'static str {
+        match self {
+            OrganizationAggregate::Organization => "organization",
+            OrganizationAggregate::Department => "department",
+            OrganizationAggregate::Team => "team",
+            OrganizationAggregate::Role => "role",
+            OrganizationAggregate::Policy => "policy",
+            OrganizationAggregate::Resource => "resource",
+            OrganizationAggregate::Structure => "structure",
+            OrganizationAggregate::Culture => "culture",
+            OrganizationAggregate::Strategy => "strategy",
+            OrganizationAggregate::Performance => "performance",
+            OrganizationAggregate::Communication => "communication",
+            OrganizationAggregate::Change => "change",
+            OrganizationAggregate::Risk => "risk",
+            OrganizationAggregate::Vendor => "vendor",
+            OrganizationAggregate::Location => "location",
+        }
+    }
+}
+
+impl OrganizationScope {
+    /// Subject tokens for this scope (`global` or `{type}.{id}`)
+    pub fn token(&self) -> String {
+        match self {
+            OrganizationScope::Global => "global".to_string(),
+            OrganizationScope::Organization(id) => format!("org.{}", id),
+            OrganizationScope::Department(id) => format!("dept.{}", id),
+            OrganizationScope::Team(id) => format!("team.{}", id),
+            OrganizationScope::Role(id) => format!("role.{}", id),
+            OrganizationScope::Location(loc) => format!("loc.{}", loc),
+            OrganizationScope::Region(region) => format!("region.{}", region),
+            OrganizationScope::Division(id) => format!("div.{}", id),
+            OrganizationScope::Project(id) => format!("proj.{}", id),
+            OrganizationScope::CostCenter(cc) => format!("cc.{}", cc),
+            OrganizationScope::Vendor(id) => format!("vendor.{}", id),
+        }
+    }
+}
+
 impl OrganizationSubject {
     /// Creates a new organization subject with required components
     pub fn new(
@@ -193,9 +289,30 @@ impl OrganizationSubject {
     }
     
     /// Builder pattern for adding optional components
-    pub fn with_namespace(mut self, namespace: String) -> Self {
+    ///
+    /// The namespace isolates tenants, so it must be a single plain token
+    /// that cannot be mistaken for a root (see `validate_namespace`).
+    pub fn with_namespace(mut self, namespace: String) -> Result<Self, SubjectParseError> {
+        Self::validate_namespace(&namespace)?;
         self.namespace = Some(namespace);
-        self
+        Ok(self)
+    }
+    
+    /// Reject namespaces that would make a subject ambiguous
+    ///
+    /// A namespace may not be empty, equal a root token such as `events`,
+    /// or contain `.`, the `*`/`>` wildcards or whitespace.
+    pub fn validate_namespace(namespace: &str) -> Result<(), SubjectParseError> {
+        let reason = if namespace.is_empty() {
+            "must not be empty"
+        } else if OrganizationSubjectRoot::is_root_token(namespace) {
+            "is a reserved root token"
+        } else if namespace.contains(['.', '*', '>']) || namespace.contains(char::is_whitespace) {
+            "must not contain '.', '*', '>' or whitespace"
+        } else {
+            return Ok(());
+        };
+        Err(SubjectParseError::InvalidNamespace(format!("'{}' {}", namespace, reason)))
     }
     
     pub fn with_operation(mut self, operation: String) -> Self {
@@ -223,53 +340,16 @@ impl OrganizationSubject {
         }
         
         // Add root pattern
-        parts.push(match &self.root {
-            OrganizationSubjectRoot::Events => "events".to_string(),
-            OrganizationSubjectRoot::Commands => "commands".to_string(),
-            OrganizationSubjectRoot::Queries => "queries".to_string(),
-            OrganizationSubjectRoot::Workflows => "workflows".to_string(),
-            OrganizationSubjectRoot::System => "system".to_string(),
-            OrganizationSubjectRoot::Analytics => "analytics".to_string(),
-            OrganizationSubjectRoot::Compliance => "compliance".to_string(),
-            OrganizationSubjectRoot::Integration => "integration".to_string(),
-        });
+        parts.push(self.root.token().to_string());
         
         // Add domain
         parts.push(self.domain.clone());
         
         // Add aggregate
-        parts.push(match &self.aggregate {
-            OrganizationAggregate::Organization => "organization".to_string(),
-            OrganizationAggregate::Department => "department".to_string(),
-            OrganizationAggregate::Team => "team".to_string(),
-            OrganizationAggregate::Role => "role".to_string(),
-            OrganizationAggregate::Policy => "policy".to_string(),
-            OrganizationAggregate::Resource => "resource".to_string(),
-            OrganizationAggregate::Structure => "structure".to_string(),
-            OrganizationAggregate::Culture => "culture".to_string(),
-            OrganizationAggregate::Strategy => "strategy".to_string(),
-            OrganizationAggregate::Performance => "performance".to_string(),
-            OrganizationAggregate::Communication => "communication".to_string(),
-            OrganizationAggregate::Change => "change".to_string(),
-            OrganizationAggregate::Risk => "risk".to_string(),
-            OrganizationAggregate::Vendor => "vendor".to_string(),
-            OrganizationAggregate::Location => "location".to_string(),
-        });
+        parts.push(self.aggregate.token().to_string());
         
         // Add scope
-        parts.push(match &self.scope {
-            OrganizationScope::Global => "global".to_string(),
-            OrganizationScope::Organization(id) => format!("org.{}", id),
-            OrganizationScope::Department(id) => format!("dept.{}", id),
-            OrganizationScope::Team(id) => format!("team.{}", id),
-            OrganizationScope::Role(id) => format!("role.{}", id),
-            OrganizationScope::Location(loc) => format!("loc.{}", loc),
-            OrganizationScope::Region(region) => format!("region.{}", region),
-            OrganizationScope::Division(id) => format!("div.{}", id),
-            OrganizationScope::Project(id) => format!("proj.{}", id),
-            OrganizationScope::CostCenter(cc) => format!("cc.{}", cc),
-            OrganizationScope::Vendor(id) => format!("vendor.{}", id),
-        });
+        parts.push(self.scope.token());
         
         // Add operation if present
         if let Some(operation) = &self.operation {
@@ -281,8 +361,11 @@ impl OrganizationSubject {
             parts.push(entity_id.clone());
         }
         
-        // Add context parameters as key-value pairs
-        for (key, value) in &self.context {
+        // Add context parameters as key-value pairs, sorted by key so the
+        // subject does not depend on HashMap iteration order
+        let mut context: Vec<_> = self.context.iter().collect();
+        context.sort();
+        for (key, value) in context {
             parts.push(format!("{}={}", key, value));
         }
         
@@ -300,7 +383,8 @@ impl OrganizationSubject {
         let mut idx = 0;
         
         // Check for namespace (optional)
-        let namespace = if parts.len() > 5 && !matches!(parts[0], "events" | "commands" | "queries" | "workflows" | "system" | "analytics" | "compliance" | "integration") {
+        let namespace = if parts.len() > 5 && !OrganizationSubjectRoot::is_root_token(parts[0]) {
+            Self::validate_namespace(parts[0])?;
             let ns = Some(parts[0].to_string());
             idx += 1;
             ns
@@ -355,8 +439,11 @@ impl OrganizationSubject {
         }
         
         let scope = if parts[idx] == "global" {
+            idx += 1;
             OrganizationScope::Global
-        } else if let Some((scope_type, scope_id)) = parts[idx].split_once('.') {
+        } else if let Some(scope_id) = parts.get(idx + 1).copied() {
+            let scope_type = parts[idx];
+            idx += 2;
             match scope_type {
                 "org" => OrganizationScope::Organization(
                     Uuid::parse_str(scope_id).map_err(|_| SubjectParseError::InvalidUuid(scope_id.to_string()))?
@@ -382,12 +469,11 @@ impl OrganizationSubject {
                 "vendor" => OrganizationScope::Vendor(
                     Uuid::parse_str(scope_id).map_err(|_| SubjectParseError::InvalidUuid(scope_id.to_string()))?
                 ),
-                _ => return Err(SubjectParseError::InvalidScope(parts[idx].to_string())),
+                _ => return Err(SubjectParseError::InvalidScope(scope_type.to_string())),
             }
         } else {
             return Err(SubjectParseError::InvalidScope(parts[idx].to_string()));
         };
-        idx += 1;
         
         // Parse operation (optional)
         let operation = if idx < parts.len() && !parts[idx].contains('=') {
@@ -430,105 +516,55 @@ impl OrganizationSubject {
     
     /// Creates a wildcard subject for subscribing to multiple related subjects
     pub fn to_wildcard_string(&self, wildcard_level: WildcardLevel) -> String {
+        let prefix = match &self.namespace {
+            Some(namespace) => format!("{}.", namespace),
+            None => String::new(),
+        };
+        
         match wildcard_level {
-            WildcardLevel::Operation => {
-                format!("{}.{}.{}.{}.{}.*", 
-                    self.namespace.as_deref().unwrap_or(""),
-                    match &self.root {
-                        OrganizationSubjectRoot::Events => "events",
-                        OrganizationSubjectRoot::Commands => "commands",
-                        OrganizationSubjectRoot::Queries => "queries",
-                        OrganizationSubjectRoot::Workflows => "workflows",
-                        OrganizationSubjectRoot::System => "system",
-                        OrganizationSubjectRoot::Analytics => "analytics",
-                        OrganizationSubjectRoot::Compliance => "compliance",
-                        OrganizationSubjectRoot::Integration => "integration",
-                    },
-                    &self.domain,
-                    match &self.aggregate {
-                        OrganizationAggregate::Organization => "organization",
-                        OrganizationAggregate::Department => "department",
-                        OrganizationAggregate::Team => "team",
-                        OrganizationAggregate::Role => "role",
-                        OrganizationAggregate::Policy => "policy",
-                        OrganizationAggregate::Resource => "resource",
-                        OrganizationAggregate::Structure => "structure",
-                        OrganizationAggregate::Culture => "culture",
-                        OrganizationAggregate::Strategy => "strategy",
-                        OrganizationAggregate::Performance => "performance",
-                        OrganizationAggregate::Communication => "communication",
-                        OrganizationAggregate::Change => "change",
-                        OrganizationAggregate::Risk => "risk",
-                        OrganizationAggregate::Vendor => "vendor",
-                        OrganizationAggregate::Location => "location",
-                    },
-                    match &self.scope {
-                        OrganizationScope::Global => "global",
-                        OrganizationScope::Organization(id) => &format!("org.{}", id),
-                        OrganizationScope::Department(id) => &format!("dept.{}", id),
-                        OrganizationScope::Team(id) => &format!("team.{}", id),
-                        OrganizationScope::Role(id) => &format!("role.{}", id),
-                        OrganizationScope::Location(loc) => &format!("loc.{}", loc),
-                        OrganizationScope::Region(region) => &format!("region.{}", region),
-                        OrganizationScope::Division(id) => &format!("div.{}", id),
-                        OrganizationScope::Project(id) => &format!("proj.{}", id),
-                        OrganizationScope::CostCenter(cc) => &format!("cc.{}", cc),
-                        OrganizationScope::Vendor(id) => &format!("vendor.{}", id),
-                    }
-                ).trim_start_matches('.').to_string()
-            },
-            WildcardLevel::Scope => {
-                format!("{}.{}.{}.{}.*", 
-                    self.namespace.as_deref().unwrap_or(""),
-                    match &self.root {
-                        OrganizationSubjectRoot::Events => "events",
-                        OrganizationSubjectRoot::Commands => "commands",
-                        OrganizationSubjectRoot::Queries => "queries",
-                        OrganizationSubjectRoot::Workflows => "workflows",
-                        OrganizationSubjectRoot::System => "system",
-                        OrganizationSubjectRoot::Analytics => "analytics",
-                        OrganizationSubjectRoot::Compliance => "compliance",
-                        OrganizationSubjectRoot::Integration => "integration",
-                    },
-                    &self.domain,
-                    match &self.aggregate {
-                        OrganizationAggregate::Organization => "organization",
-                        OrganizationAggregate::Department => "department",
-                        OrganizationAggregate::Team => "team",
-                        OrganizationAggregate::Role => "role",
-                        OrganizationAggregate::Policy => "policy",
-                        OrganizationAggregate::Resource => "resource",
-                        OrganizationAggregate::Structure => "structure",
-                        OrganizationAggregate::Culture => "culture",
-                        OrganizationAggregate::Strategy => "strategy",
-                        OrganizationAggregate::Performance => "performance",
-                        OrganizationAggregate::Communication => "communication",
-                        OrganizationAggregate::Change => "change",
-                        OrganizationAggregate::Risk => "risk",
-                        OrganizationAggregate::Vendor => "vendor",
-                        OrganizationAggregate::Location => "location",
-                    }
-                ).trim_start_matches('.').to_string()
-            },
-            WildcardLevel::Aggregate => {
-                format!("{}.{}.{}.*", 
-                    self.namespace.as_deref().unwrap_or(""),
-                    match &self.root {
-                        OrganizationSubjectRoot::Events => "events",
-                        OrganizationSubjectRoot::Commands => "commands",
-                        OrganizationSubjectRoot::Queries => "queries",
-                        OrganizationSubjectRoot::Workflows => "workflows",
-                        OrganizationSubjectRoot::System => "system",
-                        OrganizationSubjectRoot::Analytics => "analytics",
-                        OrganizationSubjectRoot::Compliance => "compliance",
-                        OrganizationSubjectRoot::Integration => "integration",
-                    },
-                    &self.domain
-                ).trim_start_matches('.').to_string()
-            },
+            WildcardLevel::Operation => format!(
+                "{}{}.{}.{}.{}.*",
+                prefix,
+                self.root.token(),
+                self.domain,
+                self.aggregate.token(),
+                self.scope.token()
+            ),
+            WildcardLevel::Scope => format!(
+                "{}{}.{}.{}.*",
+                prefix,
+                self.root.token(),
+                self.domain,
+                self.aggregate.token()
+            ),
+            WildcardLevel::Aggregate => format!(
+                "{}{}.{}.*",
+                prefix,
+                self.root.token(),
+                self.domain
+            ),
             WildcardLevel::All => ">".to_string(),
         }
     }
+    
+    /// Organization the subject concerns, when the subject encodes it
+    ///
+    /// Taken from an organization scope, then a `parent_org` context entry,
+    /// then the entity id of an organization-aggregate subject such as
+    /// `organization_created`. Other scopes name a department, team or the
+    /// like rather than the organization, so those subjects yield `None`.
+    pub fn organization_id(&self) -> Option<Uuid> {
+        if let OrganizationScope::Organization(id) = &self.scope {
+            return Some(*id);
+        }
+        if let Some(id) = self.context.get("parent_org").and_then(|id| Uuid::parse_str(id).ok()) {
+            return Some(id);
+        }
+        match (&self.aggregate, &self.entity_id) {
+            (OrganizationAggregate::Organization, Some(id)) => Uuid::parse_str(id).ok(),
+            _ => None,
+        }
+    }
 }
 
 /// Wildcard levels for NATS subscriptions
@@ -547,6 +583,62 @@ pub enum WildcardLevel {
     All,
 }
 
+/// Subscription plan generator for event consumers
+///
+/// Produces the smallest set of event subjects covering the requested
+/// aggregates, collapsing to an aggregate wildcard when every aggregate
+/// is requested.
+pub struct SubscriptionPlan;
+
+impl SubscriptionPlan {
+    /// Event subjects covering `aggregates`, limited to `scope` when given
+    ///
+    /// A tenant `namespace` prefixes every subject, so the plan stays inside
+    /// that tenant; it is validated like `OrganizationSubject::with_namespace`.
+    /// A scoped plan lists the bare scope subject as well as `{scope}.>`,
+    /// since `>` does not match a subject that ends at the scope.
+    pub fn for_aggregates(
+        aggregates: &[OrganizationAggregate],
+        scope: Option<&OrganizationScope>,
+        namespace: Option<&str>,
+    ) -> Result<Vec<String>, SubjectParseError> {
+        if let Some(namespace) = namespace {
+            OrganizationSubject::validate_namespace(namespace)?;
+        }
+
+        let mut requested: Vec<&OrganizationAggregate> = Vec::new();
+        for aggregate in aggregates {
+            if !requested.contains(&aggregate) {
+                requested.push(aggregate);
+            }
+        }
+
+        if requested.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut prefix = format!("{}.{}", OrganizationSubjectRoot::Events.token(), ORGANIZATION_DOMAIN);
+        if let Some(namespace) = namespace {
+            prefix = format!("{}.{}", namespace, prefix);
+        }
+        let prefix = prefix.as_str();
+        let suffixes = match scope {
+            Some(scope) => vec![scope.token(), format!("{}.>", scope.token())],
+            None => vec![">".to_string()],
+        };
+        let aggregate_tokens: Vec<&str> = if OrganizationAggregate::ALL.iter().all(|a| requested.contains(&a)) {
+            vec!["*"]
+        } else {
+            requested.into_iter().map(|aggregate| aggregate.token()).collect()
+        };
+
+        Ok(aggregate_tokens
+            .into_iter()
+            .flat_map(|token| suffixes.iter().map(move |suffix| format!("{}.{}.{}", prefix, token, suffix)))
+            .collect())
+    }
+}
+
 /// Organization-specific subject pattern builders for common operations
 impl OrganizationSubject {
     // Organization lifecycle operations
@@ -854,6 +946,35 @@ impl OrganizationSubject {
         .with_operation("restructuring_completed".to_string())
         .with_entity_id(workflow_id.to_string())
     }
+    
+    // Query request/reply patterns
+    pub fn query_organization(org_id: Uuid) -> Self {
+        Self::new(
+            OrganizationSubjectRoot::Queries,
+            OrganizationAggregate::Organization,
+            OrganizationScope::Organization(org_id),
+        )
+        .with_operation("get".to_string())
+    }
+    
+    /// Positions only; people holding them are queried in the Association domain
+    pub fn query_roles(org_id: Uuid) -> Self {
+        Self::new(
+            OrganizationSubjectRoot::Queries,
+            OrganizationAggregate::Role,
+            OrganizationScope::Organization(org_id),
+        )
+        .with_operation("list".to_string())
+    }
+    
+    pub fn query_hierarchy(org_id: Uuid) -> Self {
+        Self::new(
+            OrganizationSubjectRoot::Queries,
+            OrganizationAggregate::Structure,
+            OrganizationScope::Organization(org_id),
+        )
+        .with_operation("get".to_string())
+    }
 }
 
 impl Display for OrganizationSubject {
@@ -871,6 +992,7 @@ pub enum SubjectParseError {
     InvalidAggregate(String),
     InvalidScope(String),
     InvalidUuid(String),
+    InvalidNamespace(String),
     MissingScope,
 }
 
@@ -883,6 +1005,7 @@ impl Display for SubjectParseError {
             SubjectParseError::InvalidAggregate(aggregate) => write!(f, "Invalid aggregate: {}", aggregate),
             SubjectParseError::InvalidScope(scope) => write!(f, "Invalid scope: {}", scope),
             SubjectParseError::InvalidUuid(uuid) => write!(f, "Invalid UUID: {}", uuid),
+            SubjectParseError::InvalidNamespace(reason) => write!(f, "Invalid namespace: {}", reason),
             SubjectParseError::MissingScope => write!(f, "Missing scope specification"),
         }
     }
@@ -965,4 +1088,184 @@ mod tests {
         assert!(subject_string.contains("employee_type=full_time"));
         assert!(subject_string.contains("department=engineering"));
     }
-}
\ No newline at end of file
+    
+    /// NATS subject matching: `*` is one token, `>` one or more trailing tokens
+    fn subject_matches(pattern: &str, subject: &str) -> bool {
+        let mut subject_tokens = subject.split('.');
+        for token in pattern.split('.') {
+            match (token, subject_tokens.next()) {
+                (">", Some(_)) => return true,
+                ("*", Some(_)) => {}
+                (token, Some(actual)) if token == actual => {}
+                _ => return false,
+            }
+        }
+        subject_tokens.next().is_none()
+    }
+    
+    #[test]
+    fn test_subscription_plan_for_department_and_team() {
+        let org_id = Uuid::now_v7();
+        let other_org_id = Uuid::now_v7();
+        let scope = OrganizationScope::Organization(org_id);
+        let plan = SubscriptionPlan::for_aggregates(
+            &[
+                OrganizationAggregate::Department,
+                OrganizationAggregate::Team,
+                OrganizationAggregate::Department,
+            ],
+            Some(&scope),
+            None,
+        )
+        .unwrap();
+        
+        assert_eq!(
+            plan,
+            vec![
+                format!("events.organization.department.org.{}", org_id),
+                format!("events.organization.department.org.{}.>", org_id),
+                format!("events.organization.team.org.{}", org_id),
+                format!("events.organization.team.org.{}.>", org_id),
+            ]
+        );
+        let covered = |subject: &OrganizationSubject| {
+            plan.iter().any(|pattern| subject_matches(pattern, &subject.to_subject_string()))
+        };
+        
+        // Each generated subject covers the matching events, with or without an operation
+        assert!(covered(&OrganizationSubject::department_created(org_id, Uuid::now_v7())));
+        assert!(covered(&OrganizationSubject::team_formed(org_id, Uuid::now_v7())));
+        assert!(covered(&OrganizationSubject::new(
+            OrganizationSubjectRoot::Events,
+            OrganizationAggregate::Team,
+            scope.clone(),
+        )));
+        
+        // ...without reaching other aggregates or organizations
+        assert!(!covered(&OrganizationSubject::role_created(org_id, Uuid::now_v7())));
+        assert!(!covered(&OrganizationSubject::department_created(other_org_id, Uuid::now_v7())));
+    }
+    
+    #[test]
+    fn test_query_subjects_round_trip() {
+        let org_id = Uuid::now_v7();
+        let queries = [
+            (OrganizationSubject::query_organization(org_id), "organization", "get"),
+            (OrganizationSubject::query_roles(org_id), "role", "list"),
+            (OrganizationSubject::query_hierarchy(org_id), "structure", "get"),
+        ];
+        
+        for (subject, aggregate, operation) in queries {
+            let subject_string = subject.to_subject_string();
+            assert_eq!(
+                subject_string,
+                format!("queries.organization.{}.org.{}.{}", aggregate, org_id, operation)
+            );
+            assert_eq!(OrganizationSubject::from_subject_string(&subject_string), Ok(subject));
+        }
+    }
+    
+    #[test]
+    fn test_namespace_validation() {
+        let org_id = Uuid::now_v7();
+        let subject = OrganizationSubject::organization_updated(org_id)
+            .with_namespace("tenant-acme".to_string())
+            .unwrap();
+        let subject_string = subject.to_subject_string();
+        assert!(subject_string.starts_with("tenant-acme.events.organization."));
+        assert_eq!(OrganizationSubject::from_subject_string(&subject_string), Ok(subject));
+        
+        for namespace in ["events", "queries", "", "acme.eu", "acme*", ">"] {
+            let result = OrganizationSubject::organization_updated(org_id).with_namespace(namespace.to_string());
+            assert!(matches!(result, Err(SubjectParseError::InvalidNamespace(_))), "{:?}", namespace);
+        }
+        
+        let wildcard = format!("*.events.organization.organization.org.{}.updated", org_id);
+        assert!(matches!(
+            OrganizationSubject::from_subject_string(&wildcard),
+            Err(SubjectParseError::InvalidNamespace(_))
+        ));
+    }
+    
+    #[test]
+    fn test_subscription_plan_collapses_all_aggregates() {
+        let plan = SubscriptionPlan::for_aggregates(&OrganizationAggregate::ALL, None, None).unwrap();
+        assert_eq!(plan, vec!["events.organization.*.>".to_string()]);
+        assert!(SubscriptionPlan::for_aggregates(&[], None, None).unwrap().is_empty());
+    }
+    
+    #[test]
+    fn test_subscription_plan_stays_in_namespace() {
+        let org_id = Uuid::now_v7();
+        let plan = SubscriptionPlan::for_aggregates(&OrganizationAggregate::ALL, None, Some("tenant-acme")).unwrap();
+        assert_eq!(plan, vec!["tenant-acme.events.organization.*.>".to_string()]);
+        
+        let tenant_event = OrganizationSubject::organization_updated(org_id)
+            .with_namespace("tenant-acme".to_string())
+            .unwrap();
+        let other_tenant = OrganizationSubject::organization_updated(org_id)
+            .with_namespace("tenant-other".to_string())
+            .unwrap();
+        assert!(subject_matches(&plan[0], &tenant_event.to_subject_string()));
+        assert!(!subject_matches(&plan[0], &other_tenant.to_subject_string()));
+        assert!(!subject_matches(&plan[0], &OrganizationSubject::organization_updated(org_id).to_subject_string()));
+        
+        assert!(matches!(
+            SubscriptionPlan::for_aggregates(&OrganizationAggregate::ALL, None, Some("events")),
+            Err(SubjectParseError::InvalidNamespace(_))
+        ));
+    }
+    
+    #[test]
+    fn test_context_keys_are_sorted() {
+        let org_id = Uuid::now_v7();
+        let subject = |pairs: &[(&str, &str)]| {
+            pairs.iter().fold(OrganizationSubject::organization_updated(org_id), |subject, (key, value)| {
+                subject.with_context(key.to_string(), value.to_string())
+            })
+        };
+        let forward = subject(&[("region", "emea"), ("priority", "high"), ("channel", "web")]);
+        let reverse = subject(&[("channel", "web"), ("priority", "high"), ("region", "emea")]);
+        
+        assert_eq!(forward.to_subject_string(), reverse.to_subject_string());
+        assert!(forward
+            .to_subject_string()
+            .ends_with("channel=web.priority=high.region=emea"));
+    }
+    
+    #[test]
+    fn test_organization_id_across_scopes() {
+        let org_id = Uuid::now_v7();
+        let dept_id = Uuid::now_v7();
+        
+        assert_eq!(OrganizationSubject::department_created(org_id, dept_id).organization_id(), Some(org_id));
+        assert_eq!(OrganizationSubject::organization_created(org_id).organization_id(), Some(org_id));
+        
+        let dept_scoped = OrganizationSubject::new(
+            OrganizationSubjectRoot::Events,
+            OrganizationAggregate::Team,
+            OrganizationScope::Department(dept_id),
+        )
+        .with_operation("formed".to_string());
+        assert_eq!(dept_scoped.organization_id(), None);
+        assert_eq!(
+            dept_scoped.with_context("parent_org".to_string(), org_id.to_string()).organization_id(),
+            Some(org_id)
+        );
+        
+        let global = OrganizationSubject::new(
+            OrganizationSubjectRoot::Queries,
+            OrganizationAggregate::Role,
+            OrganizationScope::Global,
+        )
+        .with_operation("list".to_string());
+        assert_eq!(global.organization_id(), None);
+        
+        // Parsed subjects resolve the same way
+        let parsed = OrganizationSubject::from_subject_string(
+            &OrganizationSubject::team_formed(org_id, Uuid::now_v7()).to_subject_string(),
+        )
+        .unwrap();
+        assert_eq!(parsed.organization_id(), Some(org_id));
+    }
+}
@@ -68,6 +68,9 @@ pub fn event_to_subject(event: &OrganizationEvent) -> String {
         OrganizationEvent::OrganizationUpdated(_) => {
             format!("events.organization.{}.updated", org_id)
         }
+        OrganizationEvent::OrganizationMetadataUpdated(_) => {
+            format!("events.organization.{}.metadata.updated", org_id)
+        }
         OrganizationEvent::OrganizationDissolved(_) => {
             format!("events.organization.{}.dissolved", org_id)
         }
@@ -122,5 +125,11 @@ pub fn event_to_subject(event: &OrganizationEvent) -> String {
         OrganizationEvent::ChildOrganizationRemoved(_) => {
             format!("events.organization.{}.child.removed", org_id)
         }
+        OrganizationEvent::OrganizationTagAdded(_) => {
+            format!("events.organization.{}.tag.added", org_id)
+        }
+        OrganizationEvent::OrganizationTagRemoved(_) => {
+            format!("events.organization.{}.tag.removed", org_id)
+        }
     }
 }
\ No newline at end of file
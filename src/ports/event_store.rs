@@ -0,0 +1,16 @@
+//! Event store port
+//!
+//! The repository appends events through this port. `NatsEventStore` is the
+//! JetStream adapter; `InMemoryEventStore` keeps streams in memory.
+
+use async_trait::async_trait;
+use cim_domain::DomainResult;
+use uuid::Uuid;
+
+use crate::OrganizationEvent;
+
+#[async_trait]
+pub trait EventStore: Send + Sync {
+    /// Append events, in order, to the stream of the given aggregate
+    async fn append_events(&self, aggregate_id: Uuid, events: Vec<OrganizationEvent>) -> DomainResult<()>;
+}
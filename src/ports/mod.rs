@@ -1,3 +1,5 @@
 pub mod event_publisher;
+pub mod event_store;
 
-pub use event_publisher::{EventPublisher, PublishError, QueryError};
\ No newline at end of file
+pub use event_publisher::{EventPublisher, PublishError, QueryError};
+pub use event_store::EventStore;
\ No newline at end of file
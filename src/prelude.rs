@@ -0,0 +1,16 @@
+//! Commonly used organization domain types
+//!
+//! `use cim_domain_organization::prelude::*;` brings in the entities and
+//! their constructors, the aggregate, the command and event enums, and
+//! the error types.
+
+pub use crate::aggregate::{OrganizationAggregate, OrganizationState, Permission};
+pub use crate::commands::OrganizationCommand;
+pub use crate::entity::{
+    Department, DepartmentStatus, Facility, FacilityStatus, FacilityType,
+    Organization, OrganizationBuilder, OrganizationStatus, OrganizationType,
+    Role, RoleStatus, RoleType, Team, TeamStatus, TeamType,
+};
+pub use crate::events::OrganizationEvent;
+pub use crate::{OrganizationError, OrganizationResult, SizeCategory};
+pub use cim_domain::{EntityId, MessageIdentity};
@@ -0,0 +1,67 @@
+//! Curated role sets for seeding organizations
+//!
+//! Each template lists its roles managers first, so every `reports_to`
+//! names a role that appears earlier in the same set. Seed them with
+//! `OrganizationAggregate::seed_roles`.
+
+use crate::entity::RoleType;
+
+/// A role to seed, identified within its template by `code`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoleTemplate {
+    pub title: &'static str,
+    pub code: &'static str,
+    pub role_type: RoleType,
+    pub level: u8,
+    /// Code of the role this one reports to, within the same template
+    pub reports_to: Option<&'static str>,
+    pub permissions: &'static [&'static str],
+}
+
+impl RoleTemplate {
+    const fn new(
+        title: &'static str,
+        code: &'static str,
+        role_type: RoleType,
+        level: u8,
+        reports_to: Option<&'static str>,
+        permissions: &'static [&'static str],
+    ) -> Self {
+        Self { title, code, role_type, level, reports_to, permissions }
+    }
+}
+
+/// Engineering function: a VP, directors and managers, then IC levels
+pub fn engineering() -> Vec<RoleTemplate> {
+    vec![
+        RoleTemplate::new("VP of Engineering", "VPE", RoleType::Executive, 8, None, &["ManageDepartment", "ApproveBudget", "CreateRole", "ViewReports"]),
+        RoleTemplate::new("Director of Engineering", "DIRENG", RoleType::Management, 7, Some("VPE"), &["ManageDepartment", "ManageTeam", "ViewReports"]),
+        RoleTemplate::new("Engineering Manager", "EM", RoleType::Management, 6, Some("DIRENG"), &["ManageTeam", "ViewReports"]),
+        RoleTemplate::new("Staff Engineer", "STAFF", RoleType::Technical, 5, Some("DIRENG"), &[]),
+        RoleTemplate::new("Senior Software Engineer", "SSE", RoleType::Technical, 4, Some("EM"), &[]),
+        RoleTemplate::new("Software Engineer", "SWE", RoleType::Technical, 3, Some("EM"), &[]),
+        RoleTemplate::new("Associate Software Engineer", "ASE", RoleType::Technical, 2, Some("EM"), &[]),
+    ]
+}
+
+/// Sales function: a VP, regional and team management, then sellers
+pub fn sales() -> Vec<RoleTemplate> {
+    vec![
+        RoleTemplate::new("VP of Sales", "VPS", RoleType::Executive, 8, None, &["ManageDepartment", "ApproveBudget", "ViewReports"]),
+        RoleTemplate::new("Regional Sales Director", "RSD", RoleType::Management, 7, Some("VPS"), &["ManageDepartment", "ViewReports"]),
+        RoleTemplate::new("Sales Manager", "SM", RoleType::Management, 6, Some("RSD"), &["ManageTeam", "ViewReports"]),
+        RoleTemplate::new("Account Executive", "AE", RoleType::Operational, 4, Some("SM"), &[]),
+        RoleTemplate::new("Sales Development Representative", "SDR", RoleType::Operational, 2, Some("SM"), &[]),
+    ]
+}
+
+/// Finance function: a CFO, a controller, then accounting staff
+pub fn finance() -> Vec<RoleTemplate> {
+    vec![
+        RoleTemplate::new("Chief Financial Officer", "CFO", RoleType::Executive, 9, None, &["ApproveBudget", "ManageDepartment", "ViewReports"]),
+        RoleTemplate::new("Controller", "CTRL", RoleType::Management, 7, Some("CFO"), &["ApproveBudget", "ManageTeam", "ViewReports"]),
+        RoleTemplate::new("Financial Analyst", "FA", RoleType::Operational, 4, Some("CTRL"), &["ViewReports"]),
+        RoleTemplate::new("Accountant", "ACCT", RoleType::Operational, 3, Some("CTRL"), &["ViewReports"]),
+        RoleTemplate::new("Accounts Payable Clerk", "APC", RoleType::Administrative, 2, Some("ACCT"), &[]),
+    ]
+}